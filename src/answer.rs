@@ -0,0 +1,223 @@
+use std::fmt::Display;
+
+use serde_json::{json, Value};
+
+use crate::ocr::{self, OcrError};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// A picture drawn over several lines, such as day10's CRT screen.
+    Grid(Vec<String>),
+    /// A picture that spells out `text`, see [`crate::ocr`]. It displays as
+    /// the text, or as the picture with the alternate flag (`{:#}`).
+    Letters {
+        text: String,
+        picture: Vec<String>,
+    },
+    /// A picture meant to spell out letters that could not be read. It
+    /// displays as the picture, and keeps the reason to report.
+    Unreadable {
+        picture: Vec<String>,
+        error: OcrError,
+    },
+    /// No answer, because the input has none for this part, along with why.
+    /// It never matches an expected answer.
+    Unsolved(String),
+}
+
+impl Answer {
+    pub fn grid(rows: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Reads the letters drawn by `rows`, falling back to the picture and
+    /// the reason it could not be read if it does not spell anything.
+    pub fn letters(rows: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let picture = rows.into_iter().map(Into::into).collect::<Vec<String>>();
+        match ocr::recognize(&picture) {
+            Ok(text) => Answer::Letters { text, picture },
+            Err(error) => Answer::Unreadable { picture, error },
+        }
+    }
+
+    /// Why the letters of this answer could not be read, if they could not.
+    pub fn ocr_error(&self) -> Option<&OcrError> {
+        match self {
+            Answer::Unreadable { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Why this part has no answer, if it has none.
+    pub fn unsolved(&self) -> Option<&str> {
+        match self {
+            Answer::Unsolved(reason) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Compares against an expected answer as written down by hand, so
+    /// surrounding whitespace and trailing whitespace on grid rows are
+    /// ignored. Letters match either their text or their picture.
+    pub fn matches(&self, expected: &str) -> bool {
+        let grid_matches = |rows: &[String]| {
+            rows.iter()
+                .map(|row| row.trim_end())
+                .eq(expected.trim_matches('\n').lines().map(str::trim_end))
+        };
+
+        match self {
+            Answer::Int(value) => expected.trim().parse::<i64>() == Ok(*value),
+            Answer::Text(text) => text.trim() == expected.trim(),
+            Answer::Grid(rows) | Answer::Unreadable { picture: rows, .. } => grid_matches(rows),
+            Answer::Letters { text, picture } => {
+                text.trim() == expected.trim() || grid_matches(picture)
+            }
+            Answer::Unsolved(..) => false,
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Grid(rows) | Answer::Unreadable { picture: rows, .. } => rows.len() > 1,
+            _ => false,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Int(value) => json!(value),
+            Answer::Text(text) => json!(text),
+            Answer::Grid(rows) | Answer::Unreadable { picture: rows, .. } => json!(rows),
+            Answer::Letters { text, .. } => json!(text),
+            Answer::Unsolved(..) => Value::Null,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) | Answer::Unreadable { picture: rows, .. } => {
+                write!(f, "{}", rows.join("\n"))
+            }
+            Answer::Letters { picture, .. } if f.alternate() => {
+                write!(f, "{}", picture.join("\n"))
+            }
+            Answer::Letters { text, .. } => write!(f, "{}", text),
+            Answer::Unsolved(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Int(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(68292).to_string(), "68292");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::grid(["#..", ".#."]).to_string(), "#..\n.#.");
+        assert_eq!(
+            Answer::Unsolved("no marker".to_string()).to_string(),
+            "no answer: no marker"
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        assert_eq!(Answer::from(24000usize), "24000");
+        assert_ne!(Answer::from(24000usize), "2400");
+        assert_eq!(Answer::from("2=-1=0"), " 2=-1=0\n");
+        assert_eq!(Answer::grid(["#..", ".#."]), "\n#..\n.#.\n");
+        assert_ne!(Answer::grid(["#..", ".#."]), "#..");
+        assert_ne!(Answer::Unsolved("no marker".to_string()), "no marker");
+    }
+
+    #[test]
+    fn test_letters() {
+        let picture = [
+            "#..#.#...",
+            "#..#.#...",
+            "####.#...",
+            "#..#.#...",
+            "#..#.#...",
+            "#..#.####",
+        ];
+        let answer = Answer::letters(picture);
+        assert_eq!(answer.to_string(), "HL");
+        assert_eq!(format!("{:#}", answer), picture.join("\n"));
+        assert!(!answer.is_multiline());
+        assert_eq!(answer, "HL");
+        assert_eq!(answer, picture.join("\n"));
+        assert_eq!(answer.to_json(), json!("HL"));
+
+        // a picture that is not made of letters stays a picture, along with
+        // the reason
+        let rows = picture[1..].to_vec();
+        let answer = Answer::letters(rows.clone());
+        assert_eq!(answer.ocr_error(), Some(&OcrError::Height(5)));
+        assert_eq!(answer.to_string(), rows.join("\n"));
+        assert_eq!(answer, rows.join("\n"));
+        assert!(answer.is_multiline());
+        assert_eq!(answer.to_json(), json!(rows));
+        assert_eq!(Answer::letters(picture).ocr_error(), None);
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(Answer::from(1).to_json(), json!(1));
+        assert_eq!(Answer::from("CMZ").to_json(), json!("CMZ"));
+        assert_eq!(Answer::grid(["#.", ".#"]).to_json(), json!(["#.", ".#"]));
+    }
+}
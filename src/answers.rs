@@ -0,0 +1,417 @@
+//! Expected answers, kept next to the personal inputs instead of in the
+//! source code. Each `answers.txt` lists the answer of every solved part:
+//!
+//! ```text
+//! part1: 68292
+//! part2: 203203
+//! ```
+//!
+//! Multi-line answers start on the line after an empty `partN:` header.
+//!
+//! The answers that used to be hard-coded in the tests are kept in
+//! `known_answers/`, laid out like the inputs, and fill in for the parts
+//! that the `answers.txt` files of the `actual_inputs` checkout lack. Other
+//! answers can be seeded with `aoc verify --record`, which writes down
+//! whatever the current solutions give for the parts that have no expected
+//! answer yet.
+
+use std::{fmt::Display, path::Path};
+
+use crate::{
+    input::{self, InputDir, InputError},
+    parse::ParseError,
+    Answer, DynSolution, Part,
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+        let mut current: Option<(Part, Vec<&str>)> = None;
+
+        fn finish(answers: &mut Answers, current: Option<(Part, Vec<&str>)>) {
+            if let Some((part, lines)) = current {
+                let value = lines.join("\n").trim_end().to_string();
+                if !value.is_empty() {
+                    *answers.get_mut(part) = Some(value);
+                }
+            }
+        }
+
+        for (index, line) in text.lines().enumerate() {
+            let header = line.split_once(':').and_then(|(key, rest)| match key {
+                "part1" => Some((Part::One, rest.trim())),
+                "part2" => Some((Part::Two, rest.trim())),
+                _ => None,
+            });
+
+            match (header, current.as_mut()) {
+                (Some((part, rest)), _) => {
+                    finish(&mut answers, current.take());
+                    if answers.get(part).is_some() {
+                        return Err(format!("line {}: part {} listed twice", index + 1, part));
+                    }
+                    let lines = if rest.is_empty() { vec![] } else { vec![rest] };
+                    current = Some((part, lines));
+                }
+                (None, Some((_, lines))) => lines.push(line),
+                (None, None) if line.trim().is_empty() => {}
+                (None, None) => {
+                    return Err(format!("line {}: expected `part1:` or `part2:`", index + 1));
+                }
+            }
+        }
+        finish(&mut answers, current);
+
+        Ok(answers)
+    }
+
+    /// Reads the `answers.txt` of a day. For the `actual_inputs` checkout,
+    /// the known answers fill in the parts it has no answer for, and stand
+    /// in for it when it does not exist.
+    pub fn load(dir: &InputDir, year: u16, day: u8) -> Result<Self, InputError> {
+        let answers = Self::read(dir, year, day);
+        if *dir != InputDir::checkout() {
+            return answers;
+        }
+        let known = match Self::read(&known_answers(), year, day) {
+            Ok(known) => known,
+            Err(InputError::Missing(..)) => return answers,
+            Err(err) => return Err(err),
+        };
+
+        let mut answers = match answers {
+            Ok(answers) => answers,
+            Err(InputError::Missing(..)) => Answers::default(),
+            Err(err) => return Err(err),
+        };
+        for part in Part::BOTH {
+            if answers.get(part).is_none() {
+                *answers.get_mut(part) = known.get(part).map(str::to_string);
+            }
+        }
+        Ok(answers)
+    }
+
+    fn read(dir: &InputDir, year: u16, day: u8) -> Result<Self, InputError> {
+        let path = dir.answers_path(year, day);
+        let text = input::read(path.clone())?;
+        Self::parse(&text).map_err(|reason| InputError::Invalid(path, reason))
+    }
+
+    pub fn save(&self, dir: &InputDir, year: u16, day: u8) -> Result<(), String> {
+        let path = dir.answers_path(year, day);
+        std::fs::write(&path, self.to_string())
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    /// Compares `actual` against the expected answer to `part`, if known.
+    pub fn verdict(&self, part: Part, actual: Answer) -> Verdict {
+        match self.get(part) {
+            Some(expected) if actual.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                actual,
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing("no expected answer".to_string()),
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// Writes the answers in the format that [`Answers::parse`] reads.
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for part in Part::BOTH {
+            match self.get(part) {
+                Some(answer) if answer.contains('\n') => writeln!(f, "part{}:\n{}", part, answer)?,
+                Some(answer) => writeln!(f, "part{}: {}", part, answer)?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The answers known to be right for the inputs of the `actual_inputs`
+/// checkout.
+pub fn known_answers() -> InputDir {
+    InputDir::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("known_answers"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        actual: Answer,
+        expected: String,
+    },
+    Missing(String),
+    /// The input or `answers.txt` exists but could not be read, so nothing
+    /// was checked.
+    Unreadable(String),
+    /// The input could not be parsed, so no part was solved.
+    Invalid(ParseError),
+}
+
+/// Solves every part of `solution` that has an expected answer, and compares
+/// the results against the store.
+pub fn verify(dir: &InputDir, solution: &dyn DynSolution) -> Vec<(Part, Verdict)> {
+    // only files that are not there at all leave the parts unchecked
+    let missing = |err: InputError| {
+        let verdict = match err {
+            InputError::Missing(path) => Verdict::Missing(format!("no {}", path.display())),
+            err => Verdict::Unreadable(err.to_string()),
+        };
+        Part::BOTH
+            .into_iter()
+            .map(|part| (part, verdict.clone()))
+            .collect()
+    };
+
+    let year = solution.year();
+    let answers = match Answers::load(dir, year, solution.day()) {
+        Ok(answers) => answers,
+        Err(err) => return missing(err),
+    };
+    let input = match dir.load(year, solution.day()) {
+        Ok(input) => input,
+        Err(err) => return missing(err),
+    };
+
+    let parts = Part::BOTH
+        .into_iter()
+        .filter(|part| answers.get(*part).is_some())
+        .collect::<Vec<_>>();
+    let mut actual = match solution.solve(&input, &parts) {
+        Ok(actual) => actual.into_iter(),
+        Err(err) => {
+            return Part::BOTH
+                .into_iter()
+                .map(|part| (part, Verdict::Invalid(err.clone())))
+                .collect()
+        }
+    };
+
+    Part::BOTH
+        .into_iter()
+        .map(|part| {
+            let verdict = match answers.get(part) {
+                Some(..) => answers.verdict(part, actual.next().unwrap()),
+                None => Verdict::Missing("no expected answer".to_string()),
+            };
+            (part, verdict)
+        })
+        .collect()
+}
+
+/// Solves the parts of `solution` that have no expected answer yet, and
+/// adds their answers to the store, creating `answers.txt` if needed. The
+/// answers already there are kept, and parts left unsolved or with an
+/// empty answer, such as the missing part 2 of the last day, are not
+/// recorded. A day without an input records nothing.
+pub fn record(dir: &InputDir, solution: &dyn DynSolution) -> Result<Vec<Part>, String> {
+    let (year, day) = (solution.year(), solution.day());
+    let mut answers = match Answers::load(dir, year, day) {
+        Ok(answers) => answers,
+        Err(InputError::Missing(..)) => Answers::default(),
+        Err(err) => return Err(err.to_string()),
+    };
+    let input = match dir.load(year, day) {
+        Ok(input) => input,
+        Err(InputError::Missing(..)) => return Ok(vec![]),
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let parts = Part::BOTH
+        .into_iter()
+        .filter(|part| answers.get(*part).is_none())
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        return Ok(parts);
+    }
+    let actual = solution
+        .solve(&input, &parts)
+        .map_err(|err| format!("invalid input: {}", err))?;
+    let mut recorded = vec![];
+    for (part, answer) in parts.into_iter().zip(actual) {
+        // an empty answer would read back as no answer at all
+        let text = answer.to_string();
+        if answer.unsolved().is_none() && !text.trim().is_empty() {
+            *answers.get_mut(part) = Some(text);
+            recorded.push(part);
+        }
+    }
+    if !recorded.is_empty() {
+        answers.save(dir, year, day)?;
+    }
+
+    Ok(recorded)
+}
+
+/// Checks one part of a day against the answers store in tests, skipping
+/// when either the input or the expected answer is not available.
+#[cfg(test)]
+pub(crate) fn assert_actual<S: crate::Solution>(part: Part) {
+    let Some(input) = input::actual_input(S::YEAR, S::DAY) else {
+        return;
+    };
+    let dir = InputDir::from_env();
+    let expected = match Answers::load(&dir, S::YEAR, S::DAY) {
+        Ok(answers) => answers.get(part).map(str::to_string),
+        Err(err @ InputError::Missing(..)) => {
+            input::report_skipped(err);
+            return;
+        }
+        Err(err) => panic!("{}", err),
+    };
+    let Some(expected) = expected else {
+        input::report_skipped(format!("no expected answer for part {}", part));
+        return;
+    };
+
+    let input = S::parse(&input).unwrap_or_else(|err| panic!("invalid input: {}", err));
+    let actual = match part {
+        Part::One => S::part1(&input),
+        Part::Two => S::part2(&input),
+    };
+    assert_eq!(actual, expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1: 68292\npart2: 203203\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("68292"));
+        assert_eq!(answers.get(Part::Two), Some("203203"));
+
+        let answers = Answers::parse("part1: 13140\n").unwrap();
+        assert_eq!(answers.get(Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_multiline() {
+        let answers = Answers::parse("part1: 13140\npart2:\n##..\n#..#\n\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("13140"));
+        assert_eq!(answers.get(Part::Two), Some("##..\n#..#"));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("68292\n").is_err());
+        assert!(Answers::parse("part1: 1\npart1: 2\n").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "part1: 13140\npart2:\n##..\n#..#\n";
+        assert_eq!(Answers::parse(text).unwrap().to_string(), text);
+        assert_eq!(Answers::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_known_answers() {
+        for solution in crate::days::ALL {
+            let answers = Answers::read(&known_answers(), solution.year(), solution.day());
+            assert!(
+                !matches!(
+                    answers,
+                    Err(InputError::Invalid(..) | InputError::Unreadable(..))
+                ),
+                "{:?}",
+                answers
+            );
+        }
+        let answers = Answers::read(&known_answers(), 2022, 1).unwrap();
+        assert_eq!(answers.get(Part::One), Some("68292"));
+    }
+
+    #[test]
+    fn test_verify_bad_answers() {
+        use crate::{days::day10::Day10, Solution};
+
+        let root = std::env::temp_dir().join(format!("aoc-bad-answers-{}", std::process::id()));
+        let dir = InputDir::new(&root);
+        assert!(matches!(
+            verify(&dir, &Day10)[0],
+            (Part::One, Verdict::Missing(..))
+        ));
+
+        std::fs::create_dir_all(dir.day_dir(2022, 10)).unwrap();
+        std::fs::write(dir.input_path(2022, 10), Day10::SAMPLE).unwrap();
+        std::fs::write(dir.answers_path(2022, 10), "13140\n").unwrap();
+        assert!(verify(&dir, &Day10)
+            .iter()
+            .all(|(_, verdict)| matches!(verdict, Verdict::Unreadable(..))));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_record() {
+        use crate::{
+            days::{day10::Day10, day15::Day15, day25::Day25},
+            Solution,
+        };
+
+        let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let dir = InputDir::new(&root);
+        assert_eq!(record(&dir, &Day10), Ok(vec![]));
+
+        std::fs::create_dir_all(dir.day_dir(2022, 10)).unwrap();
+        std::fs::write(dir.input_path(2022, 10), Day10::SAMPLE).unwrap();
+        std::fs::write(dir.answers_path(2022, 10), "part1: 1\n").unwrap();
+
+        // only the part without an answer is recorded
+        assert_eq!(record(&dir, &Day10), Ok(vec![Part::Two]));
+        assert_eq!(record(&dir, &Day10), Ok(vec![]));
+        let verdicts = verify(&dir, &Day10);
+        assert!(matches!(verdicts[0], (Part::One, Verdict::Fail { .. })));
+        assert_eq!(verdicts[1], (Part::Two, Verdict::Pass));
+
+        // a part without an answer is left for later
+        std::fs::create_dir_all(dir.day_dir(2022, 15)).unwrap();
+        std::fs::write(
+            dir.input_path(2022, 15),
+            "Sensor at x=10, y=10: closest beacon is at x=30, y=10",
+        )
+        .unwrap();
+        assert_eq!(record(&dir, &Day15), Ok(vec![Part::One]));
+        assert!(matches!(
+            verify(&dir, &Day15)[1],
+            (Part::Two, Verdict::Missing(..))
+        ));
+
+        // and so is an empty one
+        std::fs::create_dir_all(dir.day_dir(2022, 25)).unwrap();
+        std::fs::write(dir.input_path(2022, 25), Day25::SAMPLE).unwrap();
+        assert_eq!(record(&dir, &Day25), Ok(vec![Part::One]));
+        assert_eq!(
+            std::fs::read_to_string(dir.answers_path(2022, 25)).unwrap(),
+            "part1: 2=-1=0\n"
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,144 @@
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::{parse::ParseError, Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    pub fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "iterations": self.iterations,
+            "parse": self.parse.to_json(),
+            "part1": self.part1.to_json(),
+            "part2": self.part2.to_json(),
+        })
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = std::hint::black_box(f());
+    (result, start.elapsed())
+}
+
+/// The answers from solving an input once, with the time each step took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse: Duration,
+    pub part1: (Answer, Duration),
+    pub part2: (Answer, Duration),
+}
+
+/// Parses and solves `input` once, timing the parse and each part.
+pub fn run_once<S: Solution>(input: &str) -> Result<Run, ParseError> {
+    let (parsed, parse) = time(|| S::parse(input));
+    let parsed = parsed?;
+    Ok(Run {
+        parse,
+        part1: time(|| S::part1(&parsed)),
+        part2: time(|| S::part2(&parsed)),
+    })
+}
+
+/// Parses and solves `input` from scratch `iterations` times, timing the
+/// parse and each part separately.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, ParseError> {
+    assert!(iterations > 0, "at least one iteration is needed");
+
+    let mut samples = [vec![], vec![], vec![]];
+
+    for _ in 0..iterations {
+        let run = run_once::<S>(input)?;
+        samples[0].push(run.parse);
+        samples[1].push(run.part1.1);
+        samples[2].push(run.part2.1);
+    }
+
+    let [parse, part1, part2] = samples.map(Stats::from_samples);
+    Ok(Timings {
+        day: S::DAY,
+        iterations,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(
+            [5, 1, 4, 2, 3]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_run_once() {
+        let run = run_once::<crate::days::day01::Day01>(crate::days::day01::Day01::SAMPLE).unwrap();
+        assert_eq!(run.part1.0, "24000");
+        assert_eq!(run.part2.0, "45000");
+    }
+
+    #[test]
+    fn test_bench() {
+        let timings =
+            bench::<crate::days::day01::Day01>(crate::days::day01::Day01::SAMPLE, 3).unwrap();
+        assert_eq!(timings.day, 1);
+        assert_eq!(timings.iterations, 3);
+        assert!(timings.part1.min <= timings.part1.median);
+        assert!(timings.part1.median <= timings.part1.max);
+    }
+}
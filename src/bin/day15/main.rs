@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022_rust::{days::day15::Day15, run};
+
+fn main() -> ExitCode {
+    run::<Day15>()
+}
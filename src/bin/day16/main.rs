@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022_rust::{days::day16::Day16, run};
+
+fn main() -> ExitCode {
+    run::<Day16>()
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use advent_of_code_2022_rust::{days::day19::Day19, run};
+
+fn main() -> ExitCode {
+    run::<Day19>()
+}
@@ -1,45 +1,7 @@
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2022/01/input.txt");
+use std::process::ExitCode;
 
-fn p1(input: &str) -> String {
-    let _input = input.trim();
-    "".to_string()
-}
-
-fn p2(input: &str) -> String {
-    let _input = input.trim();
-    "".to_string()
-}
-
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const SAMPLE_INPUT: &str = include_str!("./sample.txt");
-
-    #[test]
-    fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "");
-    }
-
-    #[test]
-    #[ignore = "not yet implemented"]
-    fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "");
-    }
-
-    #[test]
-    fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "");
-    }
+use advent_of_code_2022_rust::{days::empty::Empty, run};
 
-    #[test]
-    #[ignore = "not yet implemented"]
-    fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "");
-    }
+fn main() -> ExitCode {
+    run::<Empty>()
 }
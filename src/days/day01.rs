@@ -0,0 +1,144 @@
+//! Day 1: Calorie Counting.
+
+use itertools::Itertools;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// The total calories carried by each elf, in the order of the input.
+pub fn get_all_elves_calories(input: &str) -> Result<Vec<i32>, ParseError> {
+    let calories = parse::blocks(input)
+        .into_iter()
+        .map(|elf| {
+            elf.into_iter()
+                .map(|line| line.parse::<i32>(line.text, "a calorie count"))
+                .sum::<Result<i32, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if calories.is_empty() {
+        return Err(ParseError::end_of_input(input, "a calorie count"));
+    }
+    Ok(calories)
+}
+
+fn p1(calories: &[i32]) -> Answer {
+    calories.iter().copied().max().unwrap().into()
+}
+
+/// The calories carried by the `count` elves carrying the most, added up.
+pub fn sum_of_top(calories: &[i32], count: usize) -> i32 {
+    calories
+        .iter()
+        .copied()
+        .fold(BinaryHeap::new(), |mut acc, val| {
+            if acc.len() < count {
+                acc.push(Reverse(val));
+            } else if acc.peek().is_some_and(|min| min.0 < val) {
+                acc.pop();
+                acc.push(Reverse(val));
+            }
+            acc
+        })
+        .into_iter()
+        .map(|val| val.0)
+        .sum()
+}
+
+fn p2(calories: &[i32]) -> Answer {
+    sum_of_top(calories, 3).into()
+}
+
+/// `size.width` elves, at least the three that part 2 adds up, each carrying
+/// up to `size.height` snacks.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.width.max(3))
+        .map(|_| {
+            (0..rng.range(1..=size.height as i64))
+                .map(|_| rng.range(1000..=60000).to_string())
+                .join("\n")
+        })
+        .join("\n\n")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const SAMPLE: &'static str = include_str!("../bin/day01/sample.txt");
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        get_all_elves_calories(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day01/sample.txt");
+
+    #[test]
+    fn test_parse_error() {
+        for input in ["", "\n\n"] {
+            let err = Day01::parse(input).err().unwrap();
+            assert_eq!(err.expected, "a calorie count");
+            assert_eq!(err.found, "end of input");
+        }
+    }
+
+    #[test]
+    fn test_sum_of_top_against_sorting() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(20) + 1, rng.index(5) + 1);
+            let calories = Day01::parse(&generate(rng, size)).unwrap();
+            let sorted = calories.iter().copied().sorted().rev().collect::<Vec<_>>();
+
+            for count in 0..=calories.len() + 1 {
+                let expected = sorted.iter().take(count).sum::<i32>();
+                assert_eq!(sum_of_top(&calories, count), expected, "{:?}", calories);
+            }
+            assert_eq!(p1(&calories), sorted[0].to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day01::parse(SAMPLE_INPUT).unwrap()), "24000");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day01>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day01::parse(SAMPLE_INPUT).unwrap()), "45000");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day01>(Part::Two);
+    }
+}
@@ -0,0 +1,247 @@
+//! Day 2: Rock Paper Scissors.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn parse(line: &Line, shape: &str) -> Result<Self, ParseError> {
+        match shape {
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(line.error(shape, "a shape (A, B, C, X, Y or Z)")),
+        }
+    }
+
+    /// The shape to play against `opponent` to get `outcome`.
+    pub fn get_correct_shape(opponent: &Shape, outcome: &Outcome) -> Shape {
+        match outcome {
+            Outcome::Tie => *opponent,
+            Outcome::Win => match opponent {
+                Shape::Rock => Shape::Paper,
+                Shape::Paper => Shape::Scissors,
+                Shape::Scissors => Shape::Rock,
+            },
+            Outcome::Lose => match opponent {
+                Shape::Rock => Shape::Scissors,
+                Shape::Paper => Shape::Rock,
+                Shape::Scissors => Shape::Paper,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Tie,
+    Lose,
+}
+
+impl Outcome {
+    fn parse(line: &Line, outcome: &str) -> Result<Self, ParseError> {
+        match outcome {
+            "X" => Ok(Self::Lose),
+            "Y" => Ok(Self::Tie),
+            "Z" => Ok(Self::Win),
+            _ => Err(line.error(outcome, "an outcome (X, Y or Z)")),
+        }
+    }
+
+    /// How playing `me` against `opponent` ends for me.
+    pub fn get_outcome(opponent: &Shape, me: &Shape) -> Outcome {
+        match (opponent, me) {
+            (Shape::Rock, Shape::Paper)
+            | (Shape::Paper, Shape::Scissors)
+            | (Shape::Scissors, Shape::Rock) => Outcome::Win,
+            (Shape::Rock, Shape::Rock)
+            | (Shape::Paper, Shape::Paper)
+            | (Shape::Scissors, Shape::Scissors) => Outcome::Tie,
+            _ => Outcome::Lose,
+        }
+    }
+}
+
+/// One line of the strategy guide. The second column is read both as my
+/// shape (part 1) and as the outcome to aim for (part 2).
+pub struct Round {
+    pub opponent: Shape,
+    pub me: Shape,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let [opponent, second] = line.words("a shape")?;
+
+        Ok(Self {
+            opponent: Shape::parse(&line, opponent)?,
+            me: Shape::parse(&line, second)?,
+            outcome: Outcome::parse(&line, second)?,
+        })
+    }
+}
+
+/// A shape played and how the round ended, which is all that scores.
+pub struct Play {
+    pub me: Shape,
+    pub outcome: Outcome,
+}
+
+impl Play {
+    /// Plays the second column as my shape.
+    pub fn from_p1(round: &Round) -> Self {
+        Self {
+            outcome: Outcome::get_outcome(&round.opponent, &round.me),
+            me: round.me,
+        }
+    }
+
+    /// Plays whichever shape gets the outcome in the second column.
+    pub fn from_p2(round: &Round) -> Self {
+        Self {
+            me: Shape::get_correct_shape(&round.opponent, &round.outcome),
+            outcome: round.outcome,
+        }
+    }
+
+    pub fn get_score(&self) -> i32 {
+        let outcome_score = match self.outcome {
+            Outcome::Win => 6,
+            Outcome::Tie => 3,
+            Outcome::Lose => 0,
+        };
+        let shape_score = match self.me {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        };
+        outcome_score + shape_score
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Round>, ParseError> {
+    parse::each_line(input, "a round", Round::parse)
+}
+
+fn p1(rounds: &[Round]) -> Answer {
+    rounds
+        .iter()
+        .map(Play::from_p1)
+        .map(|play| play.get_score())
+        .sum::<i32>()
+        .into()
+}
+
+fn p2(rounds: &[Round]) -> Answer {
+    rounds
+        .iter()
+        .map(Play::from_p2)
+        .map(|play| play.get_score())
+        .sum::<i32>()
+        .into()
+}
+
+/// `size.width` rounds of the strategy guide.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.width)
+        .map(|_| {
+            let shape = rng.pick(&['A', 'B', 'C']);
+            format!("{} {}", shape, rng.pick(&['X', 'Y', 'Z']))
+        })
+        .join("\n")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const SAMPLE: &'static str = include_str!("../bin/day02/sample.txt");
+
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day02/sample.txt");
+
+    #[test]
+    fn test_rounds_score_as_planned() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(30) + 1, 1);
+            for round in Day02::parse(&generate(rng, size)).unwrap() {
+                let (mine, planned) = (Play::from_p1(&round), Play::from_p2(&round));
+                assert_eq!(
+                    Outcome::get_outcome(&round.opponent, &planned.me),
+                    round.outcome
+                );
+                assert_eq!(
+                    Shape::get_correct_shape(&round.opponent, &mine.outcome),
+                    mine.me
+                );
+                assert!((1..=9).contains(&mine.get_score()));
+                assert!((1..=9).contains(&planned.get_score()));
+            }
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("").err().unwrap();
+        assert_eq!(err.expected, "a round");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day02::parse(SAMPLE_INPUT).unwrap()), "15");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day02>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day02::parse(SAMPLE_INPUT).unwrap()), "12");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day02>(Part::Two);
+    }
+}
@@ -0,0 +1,222 @@
+//! Day 3: Rucksack Reorganization.
+
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// `a` to `z` are worth 1 to 26, and `A` to `Z` 27 to 52. Panics on any
+/// other item.
+pub fn get_priority(ch: char) -> u32 {
+    match ch {
+        'a'..='z' => ch as u32 - 'a' as u32 + 1,
+        'A'..='Z' => ch as u32 - 'A' as u32 + 27,
+        _ => panic!("{} does not have a priority", ch),
+    }
+}
+
+/// The items found in every one of `lists`.
+fn common_items<'a>(lists: impl IntoIterator<Item = &'a str>) -> HashSet<char> {
+    lists
+        .into_iter()
+        .map(|items| items.chars().collect::<HashSet<_>>())
+        .reduce(|common, items| &common & &items)
+        .unwrap_or_default()
+}
+
+/// Each rucksack's items, checked to be letters that split evenly into two
+/// compartments with exactly one item in both, and to come in groups of
+/// three with exactly one badge each.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<&str>, ParseError> {
+    let lines = parse::lines(input).collect::<Vec<_>>();
+    if lines.is_empty() {
+        return Err(ParseError::end_of_input(input, "a rucksack"));
+    }
+    for line in &lines {
+        let items = line.chars("an item (a-z or A-Z)", |ch| ch.is_ascii_alphabetic())?;
+        if items.len() % 2 != 0 {
+            return Err(line.error(&line.text[line.text.len()..], "an even number of items"));
+        }
+        let (left, right) = items.split_at(items.len() / 2);
+        if common_items([left, right]).len() != 1 {
+            return Err(line.error(items, "exactly one item in both compartments"));
+        }
+    }
+    for group in lines.chunks(3) {
+        if group.len() < 3 {
+            return Err(ParseError::end_of_input(
+                input,
+                "a group of three rucksacks",
+            ));
+        }
+        if common_items(group.iter().map(|line| line.text)).len() != 1 {
+            return Err(group[2].error(group[2].text, "exactly one item in the whole group"));
+        }
+    }
+    Ok(lines.into_iter().map(|line| line.text).collect())
+}
+
+fn p1(rucksacks: &[&str]) -> Answer {
+    rucksacks
+        .iter()
+        .flat_map(|line| common_items([&line[..line.len() / 2], &line[line.len() / 2..]]))
+        .map(get_priority)
+        .sum::<u32>()
+        .into()
+}
+
+fn p2(rucksacks: &[&str]) -> Answer {
+    rucksacks
+        .chunks(3)
+        .flat_map(|group| common_items(group.iter().copied()))
+        .map(get_priority)
+        .sum::<u32>()
+        .into()
+}
+
+/// `size.width` rucksacks, rounded up to whole groups of three, with
+/// `size.height` items in each compartment.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let half = size.height.max(2);
+    let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+
+    (0..size.width.div_ceil(3))
+        .map(|_| {
+            // the badge, the item shared by both compartments of each
+            // rucksack, and then a pool of 8 letters for every compartment
+            rng.shuffle(&mut letters);
+            let (badge, shared) = (letters[0], &letters[1..4]);
+            let pools = letters[4..].chunks(8).collect::<Vec<_>>();
+
+            (0..3)
+                .map(|i| {
+                    let mut fill = |mut items: Vec<char>, pool: &[char]| {
+                        while items.len() < half {
+                            items.push(*rng.pick(pool));
+                        }
+                        rng.shuffle(&mut items);
+                        items.into_iter().collect::<String>()
+                    };
+                    let left = fill(vec![shared[i], badge], pools[2 * i]);
+                    left + &fill(vec![shared[i]], pools[2 * i + 1])
+                })
+                .join("\n")
+        })
+        .join("\n")
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const SAMPLE: &'static str = include_str!("../bin/day03/sample.txt");
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day03/sample.txt");
+
+    #[test]
+    fn test_against_bitmasks() {
+        let mask = |items: &str| {
+            items
+                .chars()
+                .fold(0u64, |mask, ch| mask | 1 << get_priority(ch))
+        };
+        // the generator leaves exactly one item in common every time
+        let only = |mask: u64| {
+            assert_eq!(mask.count_ones(), 1);
+            mask.trailing_zeros()
+        };
+
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(12) + 1, rng.index(16) + 2);
+            let input = generate(rng, size);
+            let rucksacks = Day03::parse(&input).unwrap();
+
+            let misplaced = rucksacks
+                .iter()
+                .map(|items| {
+                    let (left, right) = items.split_at(items.len() / 2);
+                    only(mask(left) & mask(right))
+                })
+                .sum::<u32>();
+            let badges = rucksacks
+                .chunks(3)
+                .map(|group| only(mask(group[0]) & mask(group[1]) & mask(group[2])))
+                .sum::<u32>();
+            assert_eq!(p1(&rucksacks), misplaced.to_string().as_str(), "{}", input);
+            assert_eq!(p2(&rucksacks), badges.to_string().as_str(), "{}", input);
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |input: &str| {
+            let err = Day03::parse(input).unwrap_err();
+            (err.line, err.column, err.expected)
+        };
+        let group = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n";
+
+        assert_eq!(
+            error("abca\nabcd\n"),
+            (2, 1, "exactly one item in both compartments".to_string())
+        );
+        assert_eq!(
+            error(&format!("{}PmmdzqPrVvPwwTWBwg\nabca\n", group)),
+            (5, 1, "a group of three rucksacks".to_string())
+        );
+        assert_eq!(
+            error(&format!("{}ttgJtRGJQctTZtZT\n", group)),
+            (3, 1, "exactly one item in the whole group".to_string())
+        );
+        assert_eq!(error("\n"), (2, 1, "a rucksack".to_string()));
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day03::parse(SAMPLE_INPUT).unwrap()), "157");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day03>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day03::parse(SAMPLE_INPUT).unwrap()), "70");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day03>(Part::Two);
+    }
+}
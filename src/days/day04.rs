@@ -0,0 +1,173 @@
+//! Day 4: Camp Cleanup.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// The inclusive ranges of sections assigned to a pair of elves.
+pub struct Scenario {
+    pub first_range: (i32, i32),
+    pub second_range: (i32, i32),
+}
+
+impl Scenario {
+    pub fn parse_line(line: Line) -> Result<Self, ParseError> {
+        let parse_range = |range: &str| -> Result<(i32, i32), ParseError> {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| line.error(&range[range.len()..], "\"-\""))?;
+            Ok((
+                line.parse(start, "a section number")?,
+                line.parse(end, "a section number")?,
+            ))
+        };
+        let (first_range, second_range) = line.split_once(",")?;
+        Ok(Scenario {
+            first_range: parse_range(first_range)?,
+            second_range: parse_range(second_range)?,
+        })
+    }
+}
+
+/// Whether either inclusive range fully contains the other.
+pub fn range_inside(a: &(i32, i32), b: &(i32, i32)) -> bool {
+    (a.0 >= b.0 && a.1 <= b.1) || (b.0 >= a.0 && b.1 <= a.1)
+}
+
+/// Whether the inclusive ranges share at least one section.
+pub fn range_overlap(a: &(i32, i32), b: &(i32, i32)) -> bool {
+    !(a.1 < b.0 || a.0 > b.1)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Scenario>, ParseError> {
+    parse::each_line(input, "a pair of section ranges", Scenario::parse_line)
+}
+
+fn p1(scenarios: &[Scenario]) -> Answer {
+    scenarios
+        .iter()
+        .filter(|scenario| range_inside(&scenario.first_range, &scenario.second_range))
+        .count()
+        .into()
+}
+
+fn p2(scenarios: &[Scenario]) -> Answer {
+    scenarios
+        .iter()
+        .filter(|scenario| range_overlap(&scenario.first_range, &scenario.second_range))
+        .count()
+        .into()
+}
+
+/// `size.width` pairs of assignments within the first `size.height` sections.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let sections = size.height as i64;
+    let assignment = |rng: &mut Rng| {
+        let start = rng.range(1..=sections);
+        format!("{}-{}", start, rng.range(start..=sections))
+    };
+
+    (0..size.width)
+        .map(|_| {
+            let first = assignment(rng);
+            format!("{},{}", first, assignment(rng))
+        })
+        .join("\n")
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const SAMPLE: &'static str = include_str!("../bin/day04/sample.txt");
+
+    type Input<'a> = Vec<Scenario>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day04/sample.txt");
+
+    #[test]
+    fn test_against_sections() {
+        rng::check(200, |rng| {
+            let size = Size::new(1, rng.index(10) + 1);
+            let scenario = &Day04::parse(&generate(rng, size)).unwrap()[0];
+            let (first, second) = (scenario.first_range, scenario.second_range);
+            let in_first = |section: &i32| (first.0..=first.1).contains(section);
+            let in_second = |section: &i32| (second.0..=second.1).contains(section);
+
+            let inside = (first.0..=first.1).all(|s| in_second(&s))
+                || (second.0..=second.1).all(|s| in_first(&s));
+            let overlap = (first.0..=first.1).any(|s| in_second(&s));
+            assert_eq!(
+                range_inside(&first, &second),
+                inside,
+                "{:?} {:?}",
+                first,
+                second
+            );
+            assert_eq!(
+                range_overlap(&first, &second),
+                overlap,
+                "{:?} {:?}",
+                first,
+                second
+            );
+            // a pair where one contains the other always overlaps
+            assert!(!inside || overlap);
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day04::parse("").err().unwrap();
+        assert_eq!(err.expected, "a pair of section ranges");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day04::parse(SAMPLE_INPUT).unwrap()), "2");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day04>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day04::parse(SAMPLE_INPUT).unwrap()), "4");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day04>(Part::Two);
+    }
+}
@@ -0,0 +1,353 @@
+//! Day 5: Supply Stacks.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// The starting stacks, each listed from bottom to top, and the moves.
+pub struct TestCase {
+    pub stacks: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// Moves `amount` crates between stacks, which are numbered from 0 here
+/// rather than from 1 as in the input.
+pub struct Instruction {
+    pub amount: usize,
+    pub source: usize,
+    pub destination: usize,
+}
+
+impl TestCase {
+    pub fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let blocks = parse::blocks(input);
+        let (start_state, instructions) = match blocks.as_slice() {
+            [start_state, instructions] => (start_state, instructions),
+            [] => return Err(ParseError::end_of_input(input, "the stacks")),
+            [_] => {
+                return Err(ParseError::end_of_input(
+                    input,
+                    "a blank line followed by the instructions",
+                ))
+            }
+            [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "end of input")),
+        };
+
+        let labels = start_state[start_state.len() - 1];
+        let total_stacks = labels.parse::<usize>(
+            labels.text.split_whitespace().last().unwrap(),
+            "the number of stacks",
+        )?;
+
+        let mut stacks = std::iter::repeat_n(vec![], total_stacks).collect::<Vec<_>>();
+
+        start_state.iter().rev().skip(1).for_each(|line| {
+            line.text
+                .chars()
+                .skip(1)
+                .step_by(4)
+                .zip(stacks.iter_mut())
+                .filter(|(character, _)| *character != ' ')
+                .for_each(|(character, stack)| stack.push(character));
+        });
+
+        // both cranes move the same number of crates each time, so they
+        // agree on the height of every stack after every move
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        let instructions = instructions
+            .iter()
+            .map(|line| {
+                let instruction = Instruction::parse(line, &heights)?;
+                heights[instruction.source] -= instruction.amount;
+                heights[instruction.destination] += instruction.amount;
+                Ok(instruction)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            stacks,
+            instructions,
+        })
+    }
+}
+
+impl Instruction {
+    /// Reads a move between the stacks that are `heights` crates high,
+    /// checking that both stacks exist and that the source holds enough
+    /// crates.
+    pub fn parse(line: &Line, heights: &[usize]) -> Result<Self, ParseError> {
+        let total_stacks = heights.len();
+        let [_, amount, _, source, _, destination] =
+            line.words("\"move <amount> from <stack> to <stack>\"")?;
+        for (word, keyword) in line
+            .text
+            .split_whitespace()
+            .step_by(2)
+            .zip(["move", "from", "to"])
+        {
+            if word != keyword {
+                return Err(line.error(word, format!("{:?}", keyword)));
+            }
+        }
+
+        let stack = |word: &str| -> Result<usize, ParseError> {
+            line.parse::<usize>(word, "a stack number")
+                .ok()
+                .filter(|stack| (1..=total_stacks).contains(stack))
+                .map(|stack| stack - 1)
+                .ok_or_else(|| {
+                    line.error(word, format!("a stack number from 1 to {}", total_stacks))
+                })
+        };
+
+        let instruction = Instruction {
+            amount: line.parse(amount, "a number of crates")?,
+            source: stack(source)?,
+            destination: stack(destination)?,
+        };
+        let available = heights[instruction.source];
+        if instruction.amount > available {
+            return Err(line.error(
+                amount,
+                format!(
+                    "at most {} crates, as many as stack {} holds",
+                    available,
+                    instruction.source + 1
+                ),
+            ));
+        }
+        Ok(instruction)
+    }
+}
+
+/// The crate on top of each stack, if none of them is empty.
+pub fn transform_to_result(stacks: Vec<Vec<char>>) -> Answer {
+    match stacks.iter().position(Vec::is_empty) {
+        Some(empty) => Answer::Unsolved(format!("stack {} ends up empty", empty + 1)),
+        None => stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect::<String>()
+            .into(),
+    }
+}
+
+fn p1(test_case: &TestCase) -> Answer {
+    let mut stacks = test_case.stacks.clone();
+
+    test_case.instructions.iter().for_each(
+        |&Instruction {
+             amount,
+             source,
+             destination,
+         }| {
+            // the amount was checked against the height of the stack
+            (0..amount).for_each(|_| {
+                let value = stacks[source].pop().unwrap();
+                stacks[destination].push(value);
+            });
+        },
+    );
+
+    transform_to_result(stacks)
+}
+
+fn p2(test_case: &TestCase) -> Answer {
+    let mut stacks = test_case.stacks.clone();
+
+    test_case.instructions.iter().for_each(
+        |&Instruction {
+             amount,
+             source,
+             destination,
+         }| {
+            let remaining_amount = stacks[source].len() - amount;
+            let mut tail = stacks[source].split_off(remaining_amount);
+            stacks[destination].append(&mut tail);
+        },
+    );
+
+    transform_to_result(stacks)
+}
+
+/// `size.width` stacks, at most the 9 that single digit labels allow, and
+/// `size.height` moves between them. No move empties a stack, since the
+/// answer reads the crate on top of every one.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let total_stacks = size.width.clamp(2, 9);
+    let letter = |rng: &mut Rng| (b'A' + rng.below(26) as u8) as char;
+
+    let mut stacks = (0..total_stacks)
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| letter(rng))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // a spare crate, so that there is always something to move
+    let spare = rng.index(total_stacks);
+    stacks[spare].push(letter(rng));
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let drawing = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| {
+                    stack
+                        .get(level)
+                        .map_or("   ".to_string(), |letter| format!("[{}]", letter))
+                })
+                .join(" ")
+        })
+        .chain([(1..=total_stacks)
+            .map(|label| format!(" {} ", label))
+            .join(" ")])
+        .join("\n");
+
+    let moves = (0..size.height)
+        .map(|_| {
+            let source = loop {
+                let source = rng.index(total_stacks);
+                if stacks[source].len() > 1 {
+                    break source;
+                }
+            };
+            let destination = (source + 1 + rng.index(total_stacks - 1)) % total_stacks;
+            let amount = rng.range(1..=stacks[source].len() as i64 - 1) as usize;
+
+            let remaining = stacks[source].len() - amount;
+            let moved = stacks[source].split_off(remaining);
+            stacks[destination].extend(moved);
+            format!("move {} from {} to {}", amount, source + 1, destination + 1)
+        })
+        .join("\n");
+
+    format!("{}\n\n{}", drawing, moves)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const SAMPLE: &'static str = include_str!("../bin/day05/sample.txt");
+
+    type Input<'a> = TestCase;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        TestCase::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day05/sample.txt");
+
+    #[test]
+    fn test_cranes_against_each_other() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(9) + 1, rng.index(20) + 1);
+            let test_case = Day05::parse(&generate(rng, size)).unwrap();
+
+            // the 9000 lifting crates one at a time flips the pile it moves,
+            // and the 9001 lifting them all keeps it, whichever way it goes
+            let mut one_by_one = test_case.stacks.clone();
+            let mut at_once = test_case.stacks.clone();
+            for instruction in &test_case.instructions {
+                let from = one_by_one[instruction.source].len() - instruction.amount;
+                let moved = one_by_one[instruction.source].split_off(from);
+                one_by_one[instruction.destination].extend(moved.into_iter().rev());
+
+                let crane = (0..instruction.amount)
+                    .map(|_| at_once[instruction.source].pop().unwrap())
+                    .collect::<Vec<_>>();
+                at_once[instruction.destination].extend(crane.into_iter().rev());
+            }
+
+            let crates =
+                |stacks: &[Vec<char>]| stacks.iter().flatten().copied().sorted().collect_vec();
+            assert_eq!(crates(&one_by_one), crates(&test_case.stacks));
+            assert_eq!(crates(&at_once), crates(&test_case.stacks));
+            assert_eq!(p1(&test_case), transform_to_result(one_by_one));
+            assert_eq!(p2(&test_case), transform_to_result(at_once));
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day05::parse(SAMPLE_INPUT).unwrap()), "CMZ");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day05>(Part::One);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = SAMPLE_INPUT.replace("move 2 from 2 to 1", "move 2 from 4 to 1");
+        let err = Day05::parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column), (8, 13));
+        assert_eq!(err.expected, "a stack number from 1 to 3");
+
+        let err = Day05::parse(&SAMPLE_INPUT.replace("to 1", "onto 1"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (6, 15));
+
+        for input in ["", "\n"] {
+            let err = Day05::parse(input).err().unwrap();
+            assert_eq!(err.expected, "the stacks");
+            assert_eq!(err.found, "end of input");
+        }
+        let err = Day05::parse(&format!("{}\nmove 1 from 1 to 2\n", SAMPLE_INPUT))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.expected.as_str()), (11, "end of input"));
+
+        let err = Day05::parse(&SAMPLE_INPUT.replace("move 3 from 1", "move 4 from 1"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (7, 6));
+        assert_eq!(err.expected, "at most 3 crates, as many as stack 1 holds");
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        let test_case = Day05::parse("[A]\n 1   2 \n\nmove 1 from 1 to 2\n").unwrap();
+        assert_eq!(p1(&test_case).unsolved(), Some("stack 1 ends up empty"));
+        assert_eq!(p2(&test_case).unsolved(), Some("stack 1 ends up empty"));
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day05::parse(SAMPLE_INPUT).unwrap()), "MCD");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day05>(Part::Two);
+    }
+}
@@ -0,0 +1,231 @@
+//! Day 6: Tuning Trouble. Besides [`solve`], the alternative approaches to
+//! finding a marker are kept for comparison.
+
+use std::collections::HashMap;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// Same as [`solve`], comparing every pair of letters in every window.
+pub fn solve_naive(input: &str, distinct_count: usize) -> Option<usize> {
+    input
+        .trim()
+        .chars()
+        .collect::<Vec<_>>()
+        .windows(distinct_count)
+        .position(|values| {
+            (0..values.len()).all(|i| ((i + 1)..values.len()).all(|j| values[i] != values[j]))
+        })
+        .map(|position| position + distinct_count)
+}
+
+/// Same as [`solve`], sliding the window past the last repeated letter.
+pub fn solve_improved(input: &str, distinct_count: usize) -> Option<usize> {
+    let mut last_seen = HashMap::new();
+    let mut start = 0usize;
+
+    input
+        .trim()
+        .chars()
+        .enumerate()
+        .find(|(index, ch)| {
+            if let Some(pos) = last_seen.get(ch) {
+                if *pos >= start {
+                    start = *pos + 1;
+                }
+            }
+            last_seen.insert(*ch, *index);
+            index - start + 1 == distinct_count
+        })
+        .map(|(index, _)| index + 1)
+}
+
+/// Same as [`solve`], counting the letters of every window in a bitmask.
+pub fn solve_bitset(input: &str, distinct_count: usize) -> Option<usize> {
+    // uses bitset to check distinct
+    // from: https://www.reddit.com/r/adventofcode/comments/zdw0u6/comment/iz4lb8u/?utm_source=reddit&utm_medium=web2x&context=3
+    input
+        .trim()
+        .chars()
+        .collect::<Vec<_>>()
+        .windows(distinct_count)
+        .position(|w| {
+            w.iter()
+                .fold(0u32, |acc, ch| acc | 1 << (*ch as u32 - 'a' as u32))
+                .count_ones() as usize
+                == distinct_count
+        })
+        .map(|position| position + distinct_count)
+}
+
+/// The number of characters read by the end of the first `distinct_count`
+/// consecutive letters that all differ, if there are any. `input` must hold
+/// lowercase letters only.
+pub fn solve(input: &str, distinct_count: usize) -> Option<usize> {
+    // use "rolling" mask
+    fn toggle(acc: u32, ch: char) -> u32 {
+        acc ^ (1 << (ch as u32 - 'a' as u32))
+    }
+    fn toggle2(acc: u32, ch1: char, ch2: char) -> u32 {
+        toggle(toggle(acc, ch1), ch2)
+    }
+    fn all_distinct(mask: u32, distinct_count: usize) -> bool {
+        mask.count_ones() as usize == distinct_count
+    }
+
+    let mut mask = input.trim().chars().take(distinct_count).fold(0u32, toggle);
+
+    if all_distinct(mask, distinct_count) {
+        Some(distinct_count)
+    } else {
+        input
+            .trim()
+            .chars()
+            .zip(input.trim().chars().skip(distinct_count))
+            .position(|(old, new)| {
+                mask = toggle2(mask, old, new);
+                all_distinct(mask, distinct_count)
+            })
+            .map(|position| distinct_count + 1 + position)
+    }
+}
+
+/// Checks that the datastream is a single line of lowercase letters, which
+/// the bitmask based solutions rely on.
+pub fn parse_datastream(input: &str) -> Result<&str, ParseError> {
+    let mut lines = parse::lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a datastream"))?;
+    let datastream = line.chars("a lowercase letter", |ch| ch.is_ascii_lowercase())?;
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "end of input"));
+    }
+    Ok(datastream)
+}
+
+fn marker(input: &str, distinct_count: usize) -> Answer {
+    match solve(input, distinct_count) {
+        Some(count) => count.into(),
+        None => Answer::Unsolved(format!(
+            "no {} consecutive letters that all differ",
+            distinct_count
+        )),
+    }
+}
+
+fn p1(input: &str) -> Answer {
+    marker(input, 4)
+}
+
+fn p2(input: &str) -> Answer {
+    marker(input, 14)
+}
+
+/// `size.width` letters from the first `size.height` of the alphabet, then
+/// enough distinct letters for both markers to show up.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let letters = ('a'..='z').take(size.height).collect::<Vec<_>>();
+    (0..size.width)
+        .map(|_| *rng.pick(&letters))
+        .chain("zyxwvutsrqponm".chars())
+        .collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const SAMPLE: &'static str = include_str!("../bin/day06/sample.txt");
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_datastream(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    #[test]
+    fn test_strategies_agree() {
+        rng::check(300, |rng| {
+            // a few letters keep markers rare, and the distinct letters at the
+            // end make sure that there is one
+            let letters = rng.range(2..=16) as u64;
+            let mut datastream = (0..rng.range(0..=200))
+                .map(|_| (b'a' + rng.below(letters) as u8) as char)
+                .collect::<String>();
+            datastream.push_str("zyxwvutsrqponm");
+
+            for distinct_count in [4, 14] {
+                let expected = solve_naive(&datastream, distinct_count);
+                assert_eq!(solve_improved(&datastream, distinct_count), expected);
+                assert_eq!(solve_bitset(&datastream, distinct_count), expected);
+                assert_eq!(solve(&datastream, distinct_count), expected);
+            }
+        });
+    }
+
+    #[test]
+    fn test_no_marker() {
+        for datastream in ["aaaaaaa", "abc"] {
+            assert_eq!(solve_naive(datastream, 4), None);
+            assert_eq!(solve_improved(datastream, 4), None);
+            assert_eq!(solve_bitset(datastream, 4), None);
+            assert_eq!(solve(datastream, 4), None);
+        }
+        assert_eq!(
+            p2("abcdefghijklm").unsolved(),
+            Some("no 14 consecutive letters that all differ")
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), "7");
+        assert_eq!(p1("bvwbjplbgvbhsrlpgdmjqwftvncz"), "5");
+        assert_eq!(p1("nppdvjthqldpwncqszvftbrmjlhg"), "6");
+        assert_eq!(p1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), "10");
+        assert_eq!(p1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), "11");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day06>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), "19");
+        assert_eq!(p2("bvwbjplbgvbhsrlpgdmjqwftvncz"), "23");
+        assert_eq!(p2("nppdvjthqldpwncqszvftbrmjlhg"), "23");
+        assert_eq!(p2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), "29");
+        assert_eq!(p2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), "26");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day06>(Part::Two);
+    }
+}
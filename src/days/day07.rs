@@ -0,0 +1,377 @@
+//! Day 7: No Space Left On Device. [`Filesystem`] rebuilds the directory
+//! tree from a terminal transcript of `cd` and `ls` commands.
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirId(usize);
+
+#[derive(Debug)]
+pub struct File<'a> {
+    name: &'a str,
+    size: usize,
+}
+
+impl<'a> File<'a> {
+    pub fn new(name: &'a str, size: usize) -> Self {
+        Self { name, size }
+    }
+}
+
+#[derive(Debug)]
+pub struct Dir<'a> {
+    name: &'a str,
+    parent: DirId,
+    files: Vec<FileId>,
+    subdirs: Vec<DirId>,
+}
+
+impl<'a> Dir<'a> {
+    pub fn new(name: &'a str, parent: DirId) -> Self {
+        Self {
+            name,
+            parent,
+            files: vec![],
+            subdirs: vec![],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Filesystem<'a> {
+    files: Vec<File<'a>>,
+    dirs: Vec<Dir<'a>>,
+}
+
+impl<'a> Filesystem<'a> {
+    /// Replays the transcript from an empty root directory. Only
+    /// directories that an earlier `ls` listed can be entered.
+    pub fn from_input(input: &'a str) -> Result<Self, ParseError> {
+        let mut fs = Filesystem {
+            files: vec![],
+            dirs: vec![Dir::new("", DirId(0))],
+        };
+
+        let mut current = fs.root();
+        let mut listing = false;
+
+        let mut lines = parse::lines(input).peekable();
+        if lines.peek().is_none() {
+            return Err(ParseError::end_of_input(
+                input,
+                "a command starting with \"$ \"",
+            ));
+        }
+        for line in lines {
+            if let Some(command) = line.text.strip_prefix("$ ") {
+                listing = command == "ls";
+                match command {
+                    "cd /" => {
+                        current = fs.root();
+                    }
+                    "cd .." => {
+                        current = fs.parent(current);
+                    }
+                    "ls" => {}
+                    _ => match command.strip_prefix("cd ") {
+                        Some(dirname) => {
+                            current = fs
+                                .child_dir(current, dirname)
+                                .ok_or_else(|| line.error(dirname, "a directory listed by ls"))?;
+                        }
+                        None => return Err(line.error(command, "\"cd\" or \"ls\"")),
+                    },
+                }
+            } else if !listing {
+                return Err(line.error(line.text, "a command starting with \"$ \""));
+            } else if let Some(dirname) = line.text.strip_prefix("dir ") {
+                fs.add_dir(current, Dir::new(dirname, current));
+            } else {
+                let (size, name) = line.split_once(" ")?;
+                fs.add_file(current, File::new(name, line.parse(size, "a file size")?));
+            }
+        }
+
+        Ok(fs)
+    }
+
+    pub fn add_file(&mut self, parent: DirId, file: File<'a>) -> FileId {
+        self.files.push(file);
+
+        let id = FileId(self.files.len() - 1);
+        self.dirs[parent.0].files.push(id);
+        id
+    }
+
+    pub fn add_dir(&mut self, parent: DirId, dir: Dir<'a>) -> DirId {
+        self.dirs.push(dir);
+
+        let id = DirId(self.dirs.len() - 1);
+        self.dirs[parent.0].subdirs.push(id);
+        id
+    }
+
+    pub fn root(&self) -> DirId {
+        DirId(0)
+    }
+
+    /// The root is its own parent.
+    pub fn parent(&self, current: DirId) -> DirId {
+        self.dirs[current.0].parent
+    }
+
+    pub fn name(&self, dir: DirId) -> &'a str {
+        self.dirs[dir.0].name
+    }
+
+    pub fn subdirs(&self, dir: DirId) -> &[DirId] {
+        &self.dirs[dir.0].subdirs
+    }
+
+    /// The name and size of every file directly inside `dir`.
+    pub fn files(&self, dir: DirId) -> impl Iterator<Item = (&'a str, usize)> + '_ {
+        self.dirs[dir.0]
+            .files
+            .iter()
+            .map(|file| (self.files[file.0].name, self.files[file.0].size))
+    }
+
+    pub fn child_dir(&self, current: DirId, name: &str) -> Option<DirId> {
+        self.dirs[current.0]
+            .subdirs
+            .iter()
+            .find(|dirid| self.dirs[dirid.0].name == name)
+            .copied()
+    }
+
+    /// The total size of every directory, nested ones included, in the order
+    /// that the directories were found in, starting with the root.
+    pub fn get_dirs_filesizes(&self) -> Vec<usize> {
+        let mut result = std::iter::repeat_n(0usize, self.dirs.len()).collect::<Vec<_>>();
+
+        fn traverse(fs: &Filesystem, result: &mut Vec<usize>, dir_id: DirId) -> usize {
+            let subdirs_size = fs.dirs[dir_id.0]
+                .subdirs
+                .iter()
+                .map(|dir_id| traverse(fs, result, *dir_id))
+                .sum::<usize>();
+            let files_size = fs.dirs[dir_id.0]
+                .files
+                .iter()
+                .map(|file_id| fs.files[file_id.0].size)
+                .sum::<usize>();
+            result[dir_id.0] = subdirs_size + files_size;
+            subdirs_size + files_size
+        }
+
+        traverse(self, &mut result, self.root());
+        result
+    }
+}
+
+fn p1(fs: &Filesystem) -> Answer {
+    fs.get_dirs_filesizes()
+        .into_iter()
+        .filter(|v| *v <= 100_000)
+        .sum::<usize>()
+        .into()
+}
+
+fn p2(fs: &Filesystem) -> Answer {
+    let mut sizes = fs.get_dirs_filesizes();
+    let used = sizes[0];
+    sizes.sort();
+    sizes
+        .into_iter()
+        .find(|s| used - s <= 40_000_000)
+        .unwrap()
+        .into()
+}
+
+/// A terminal session exploring `size.width` directories, nested at most
+/// `size.height` deep, that fill between 40M and 70M of the disk so that
+/// part 2 has to delete something.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let word = |rng: &mut Rng| {
+        (0..rng.range(1..=8))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect::<String>()
+    };
+
+    // directory 0 is the root, and every other one sits in an earlier one
+    let mut names = vec![String::new()];
+    let mut depths = vec![0];
+    let mut subdirs = vec![vec![]];
+    for dir in 1..size.width {
+        let parent = loop {
+            let parent = rng.index(dir);
+            if depths[parent] < size.height {
+                break parent;
+            }
+        };
+        let name = loop {
+            let name = word(rng);
+            if subdirs[parent]
+                .iter()
+                .all(|sibling| names[*sibling] != name)
+            {
+                break name;
+            }
+        };
+        names.push(name);
+        depths.push(depths[parent] + 1);
+        subdirs.push(vec![]);
+        subdirs[parent].push(dir);
+    }
+
+    // mostly small files with a few large ones, scaled to the disk usage
+    let mut files = (0..size.width)
+        .map(|_| {
+            (0..rng.range(0..=4))
+                .map(|_| rng.range(1..=1000).pow(3))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    files[0].push(rng.range(1..=1000).pow(3));
+    let total = files.iter().flatten().sum::<i64>();
+    let used = rng.range(40_000_001..=70_000_000);
+    files
+        .iter_mut()
+        .flatten()
+        .for_each(|file| *file = (*file * used / total).max(1));
+
+    let mut transcript = vec!["$ cd /".to_string()];
+    // directories left to explore, with None for climbing back up
+    let mut pending = vec![Some(0)];
+    while let Some(step) = pending.pop() {
+        let Some(dir) = step else {
+            transcript.push("$ cd ..".to_string());
+            continue;
+        };
+        if dir != 0 {
+            transcript.push(format!("$ cd {}", names[dir]));
+        }
+        transcript.push("$ ls".to_string());
+
+        let mut listing = subdirs[dir]
+            .iter()
+            .map(|subdir| format!("dir {}", names[*subdir]))
+            .collect::<Vec<_>>();
+        for file in &files[dir] {
+            let mut name = word(rng);
+            if rng.chance(0.5) {
+                name = format!("{}.{}", name, word(rng));
+            }
+            listing.push(format!("{} {}", file, name));
+        }
+        rng.shuffle(&mut listing);
+        transcript.extend(listing);
+
+        for subdir in subdirs[dir].iter().rev() {
+            pending.extend([None, Some(*subdir)]);
+        }
+    }
+
+    transcript.join("\n")
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const SAMPLE: &'static str = include_str!("../bin/day07/sample.txt");
+
+    type Input<'a> = Filesystem<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Filesystem::from_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day07/sample.txt");
+
+    #[test]
+    fn test_dir_sizes() {
+        rng::check(50, |rng| {
+            let size = Size::new(rng.index(20) + 1, rng.index(5) + 1);
+            let input = generate(rng, size);
+            let fs = Day07::parse(&input).unwrap();
+            let sizes = fs.get_dirs_filesizes();
+
+            // every file is counted once in each directory above it
+            assert_eq!(
+                sizes[0],
+                fs.files.iter().map(|file| file.size).sum::<usize>()
+            );
+            for (dir, size) in sizes.iter().enumerate() {
+                let files = fs.files(DirId(dir)).map(|(_, size)| size).sum::<usize>();
+                let subdirs = fs
+                    .subdirs(DirId(dir))
+                    .iter()
+                    .map(|subdir| sizes[subdir.0])
+                    .sum::<usize>();
+                assert_eq!(*size, files + subdirs, "{}", input);
+            }
+
+            // part 2 deletes the smallest directory that frees enough space
+            let deleted = p2(&fs).to_string().parse::<usize>().unwrap();
+            assert!(sizes[0] - deleted <= 40_000_000);
+            assert!(sizes
+                .iter()
+                .all(|size| *size >= deleted || sizes[0] - size > 40_000_000));
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day07::parse("").err().unwrap();
+        assert_eq!(err.expected, "a command starting with \"$ \"");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day07::parse(SAMPLE_INPUT).unwrap()), "95437");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day07>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day07::parse(SAMPLE_INPUT).unwrap()), "24933642");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day07>(Part::Two);
+    }
+}
@@ -0,0 +1,199 @@
+//! Day 8: Treetop Tree House.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    grid::Grid,
+    parse::ParseError,
+    point::{Direction, Point2},
+    rng::Rng,
+    Answer, Solution,
+};
+
+pub fn parse_input(input: &str) -> Result<Grid<i32>, ParseError> {
+    let grid = Grid::parse(input, "a tree height (0-9)", |ch| {
+        ch.to_digit(10).map(|height| height as i32)
+    })?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(ParseError::end_of_input(input, "a tree height (0-9)"));
+    }
+    Ok(grid)
+}
+
+/// The number of trees that can be seen from outside the grid, looking
+/// along a row or column.
+pub fn count_visible(grid: &Grid<i32>) -> usize {
+    let mut visible = Grid::new(grid.width(), grid.height(), false);
+
+    // sweep every row and column from both ends, marking trees taller than
+    // everything before them
+    let mut sweep = |line: &mut dyn Iterator<Item = Point2<usize>>| {
+        let mut edge = -1;
+        line.for_each(|pos| {
+            if grid[pos] > edge {
+                edge = grid[pos];
+                visible[pos] = true;
+            }
+        });
+    };
+
+    (0..grid.height()).for_each(|y| {
+        sweep(&mut (0..grid.width()).map(|x| Point2::new(x, y)));
+        sweep(&mut (0..grid.width()).rev().map(|x| Point2::new(x, y)));
+    });
+
+    (0..grid.width()).for_each(|x| {
+        sweep(&mut (0..grid.height()).map(|y| Point2::new(x, y)));
+        sweep(&mut (0..grid.height()).rev().map(|y| Point2::new(x, y)));
+    });
+
+    visible
+        .rows()
+        .map(|row| row.iter().filter(|v| **v).count())
+        .sum()
+}
+
+/// The number of trees seen from `pos` in each direction, up to and
+/// including the first one at least as tall, multiplied together.
+pub fn scenic_score(grid: &Grid<i32>, pos: Point2<usize>) -> u32 {
+    Direction::ALL
+        .into_iter()
+        .map(|dir| {
+            grid.ray(pos, dir.delta())
+                .position(|other| grid[other] >= grid[pos])
+                .map(|v| v + 1)
+                .unwrap_or_else(|| grid.ray(pos, dir.delta()).count()) as u32
+        })
+        .product()
+}
+
+fn p1(grid: &Grid<i32>) -> Answer {
+    count_visible(grid).into()
+}
+
+fn p2(grid: &Grid<i32>) -> Answer {
+    grid.positions()
+        .map(|pos| scenic_score(grid, pos))
+        .max()
+        .unwrap()
+        .into()
+}
+
+/// A `size.width`x`size.height` patch of trees of random heights.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.height)
+        .map(|_| {
+            (0..size.width)
+                .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    const SAMPLE: &'static str = include_str!("../bin/day08/sample.txt");
+
+    type Input<'a> = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day08/sample.txt");
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day08::parse("").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_against_looking_around() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(10) + 1, rng.index(10) + 1);
+            let grid = Day08::parse(&generate(rng, size)).unwrap();
+            let (width, height) = (grid.width(), grid.height());
+
+            // the trees seen from `pos` along `line`, up to and including
+            // the first one at least as tall
+            let view = |pos: Point2<usize>, line: Vec<Point2<usize>>| {
+                let blocking = line.iter().position(|other| grid[*other] >= grid[pos]);
+                (
+                    blocking.map_or(line.len(), |index| index + 1),
+                    blocking.is_none(),
+                )
+            };
+            let views = |pos: Point2<usize>| {
+                let Point2 { x, y } = pos;
+                [
+                    (0..x).rev().map(|x| Point2::new(x, y)).collect(),
+                    (x + 1..width).map(|x| Point2::new(x, y)).collect(),
+                    (0..y).rev().map(|y| Point2::new(x, y)).collect(),
+                    (y + 1..height).map(|y| Point2::new(x, y)).collect(),
+                ]
+                .map(|line| view(pos, line))
+            };
+
+            let visible = grid
+                .positions()
+                .filter(|pos| views(*pos).iter().any(|(_, edge)| *edge))
+                .count();
+            let best = grid
+                .positions()
+                .map(|pos| {
+                    views(pos)
+                        .iter()
+                        .map(|(seen, _)| *seen as u32)
+                        .product::<u32>()
+                })
+                .max()
+                .unwrap();
+            assert_eq!(count_visible(&grid), visible);
+            assert_eq!(p2(&grid), best.to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day08::parse(SAMPLE_INPUT).unwrap()), "21");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day08>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day08::parse(SAMPLE_INPUT).unwrap()), "8");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day08>(Part::Two);
+    }
+}
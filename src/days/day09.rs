@@ -0,0 +1,211 @@
+//! Day 9: Rope Bridge.
+
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    point::{Direction, Point2},
+    rng::Rng,
+    visualize::{plot, Recorder},
+    Answer, Solution,
+};
+
+/// Where a knot moves to follow the knot ahead of it, `head`.
+pub fn update_tail(head: Point2, tail: Point2) -> Point2 {
+    if head.chebyshev(tail) <= 1 {
+        tail
+    } else {
+        tail + (head - tail).signum()
+    }
+}
+
+pub fn parse_motions(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    parse::each_line(input, "a motion", |line| {
+        let [dir, count] = line.words("a direction and a step count")?;
+        let dir = match dir {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return Err(line.error(dir, "a direction (L, R, U or D)")),
+        };
+        Ok((dir, line.parse(count, "a step count")?))
+    })
+}
+
+/// Moves a rope of `knots` knots, calling `on_step` after every step until
+/// it returns `false`, and returns the positions visited by the tail.
+pub fn simulate(
+    motions: &[(Direction, usize)],
+    knots: usize,
+    mut on_step: impl FnMut(&[Point2], &HashSet<Point2>) -> bool,
+) -> HashSet<Point2> {
+    let mut visited = HashSet::from([Point2::default()]);
+    let mut body = vec![Point2::default(); knots];
+
+    for &(dir, count) in motions {
+        for _ in 0..count {
+            body[0] += dir.delta();
+            (1..body.len()).for_each(|i| {
+                body[i] = update_tail(body[i - 1], body[i]);
+            });
+            visited.insert(*body.iter().last().unwrap());
+
+            if !on_step(&body, &visited) {
+                return visited;
+            }
+        }
+    }
+    visited
+}
+
+fn p1(motions: &[(Direction, usize)]) -> Answer {
+    simulate(motions, 2, |_, _| true).len().into()
+}
+
+fn p2(motions: &[(Direction, usize)]) -> Answer {
+    simulate(motions, 10, |_, _| true).len().into()
+}
+
+fn visualize(motions: &[(Direction, usize)], recorder: &mut Recorder) {
+    simulate(motions, 10, |body, visited| {
+        let knots = body.iter().enumerate().rev().map(|(i, knot)| {
+            let label = match i {
+                0 => 'H',
+                _ => char::from_digit(i as u32, 10).unwrap(),
+            };
+            (*knot, label)
+        });
+
+        recorder.record(&plot(
+            visited
+                .iter()
+                .map(|position| (*position, '#'))
+                .chain([(Point2::default(), 's')])
+                .chain(knots),
+            '.',
+        ))
+    });
+}
+
+/// `size.width` motions of up to `size.height` steps each.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.width)
+        .map(|_| {
+            let direction = rng.pick(&['U', 'D', 'L', 'R']);
+            format!("{} {}", direction, rng.range(1..=size.height as i64))
+        })
+        .join("\n")
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const SAMPLE: &'static str = include_str!("../bin/day09/sample.txt");
+
+    type Input<'a> = Vec<(Direction, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_motions(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn visualize(input: &Self::Input<'_>, recorder: &mut Recorder) -> bool {
+        visualize(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day09/sample.txt");
+
+    #[test]
+    fn test_knots_stay_together() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(30) + 1, rng.index(8) + 1);
+            let motions = Day09::parse(&generate(rng, size)).unwrap();
+
+            // a rope of a single knot visits wherever the head goes
+            let mut head = Point2::default();
+            let mut path = HashSet::from([head]);
+            for &(dir, count) in &motions {
+                for _ in 0..count {
+                    head += dir.delta();
+                    path.insert(head);
+                }
+            }
+            assert_eq!(simulate(&motions, 1, |_, _| true), path);
+
+            simulate(&motions, 10, |body, _| {
+                assert!(
+                    body.windows(2).all(|pair| pair[0].chebyshev(pair[1]) <= 1),
+                    "{:?}",
+                    body
+                );
+                true
+            });
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day09::parse("").err().unwrap();
+        assert_eq!(err.expected, "a motion");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day09::parse(SAMPLE_INPUT).unwrap()), "13");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day09>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day09::parse(SAMPLE_INPUT).unwrap()), "1");
+        assert_eq!(
+            p2(&Day09::parse(
+                r"
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"
+            )
+            .unwrap()),
+            "36"
+        );
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day09>(Part::Two);
+    }
+}
@@ -0,0 +1,285 @@
+//! Day 10: Cathode-Ray Tube.
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+impl Instruction {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        if line.text == "noop" {
+            Ok(Instruction::Noop)
+        } else {
+            let value = line.strip_prefix("addx ").map_err(|err| ParseError {
+                expected: "\"noop\" or \"addx <value>\"".to_string(),
+                ..err
+            })?;
+            Ok(Instruction::Addx(line.parse(value, "a value to add")?))
+        }
+    }
+}
+
+/// The register `x`, and the cycle that the next instruction starts on.
+#[derive(Debug)]
+pub struct Cpu {
+    pub x: i32,
+    pub cycle: usize,
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self { x: 1, cycle: 1 }
+    }
+
+    pub fn run(&mut self, instruction: &Instruction) {
+        self.cycle = self.get_instruction_cycle_range(instruction).1 + 1;
+
+        if let Instruction::Addx(value) = instruction {
+            self.x += value;
+        }
+    }
+
+    /// The first and last cycle, inclusive, that `instruction` would take if
+    /// it ran next.
+    pub fn get_instruction_cycle_range(&self, instruction: &Instruction) -> (usize, usize) {
+        match instruction {
+            Instruction::Noop => (self.cycle, self.cycle),
+            Instruction::Addx(..) => (self.cycle, self.cycle + 1),
+        }
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::each_line(input, "an instruction", Instruction::parse)
+}
+
+/// The sum of the cycle number times `x` during the 20th, 60th, ... 220th
+/// cycles.
+pub fn signal_strength(program: &[Instruction]) -> i32 {
+    let mut cpu = Cpu::new();
+    let mut cycles_left: Vec<usize> = vec![220, 180, 140, 100, 60, 20];
+    let mut result = 0;
+
+    for instruction in program {
+        if cycles_left.is_empty() {
+            break;
+        }
+
+        if cpu.get_instruction_cycle_range(instruction).1 >= *cycles_left.iter().last().unwrap() {
+            result += cycles_left.pop().unwrap() as i32 * cpu.x;
+        }
+
+        cpu.run(instruction);
+    }
+
+    result
+}
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+pub const SCREEN_SIZE: usize = SCREEN_WIDTH * SCREEN_HEIGHT;
+
+/// The rows of pixels drawn on the screen, lit ones as `#`.
+pub fn draw(program: &[Instruction]) -> Vec<String> {
+    let mut cpu = Cpu::new();
+    let mut screen = std::iter::repeat_n(
+        std::iter::repeat_n('.', SCREEN_WIDTH).collect::<Vec<_>>(),
+        SCREEN_HEIGHT,
+    )
+    .collect::<Vec<_>>();
+
+    for instruction in program {
+        if cpu.cycle > SCREEN_SIZE {
+            break;
+        }
+
+        let range = cpu.get_instruction_cycle_range(instruction);
+
+        (range.0..(range.1 + 1)).for_each(|cycle| {
+            if cycle > SCREEN_SIZE {
+                return;
+            }
+
+            let row = (cycle - 1) / SCREEN_WIDTH;
+            let col = (cycle - 1) % SCREEN_WIDTH;
+
+            if (col as i32 - cpu.x).abs() <= 1 {
+                screen[row][col] = '#';
+            }
+        });
+
+        cpu.run(instruction);
+    }
+
+    screen
+        .into_iter()
+        .map(|line| line.iter().collect())
+        .collect()
+}
+
+fn p1(program: &[Instruction]) -> Answer {
+    signal_strength(program).into()
+}
+
+fn p2(program: &[Instruction]) -> Answer {
+    Answer::letters(draw(program))
+}
+
+/// At least `size.width` instructions, and enough of them to draw the
+/// whole screen, adding values of up to `size.height` either way.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let bound = size.height as i64;
+    let mut program = vec![];
+    let mut cycles = 0;
+
+    while program.len() < size.width || cycles < SCREEN_SIZE {
+        if rng.chance(0.5) {
+            program.push("noop".to_string());
+            cycles += 1;
+        } else {
+            program.push(format!("addx {}", rng.range(-bound..=bound)));
+            cycles += 2;
+        }
+    }
+
+    program.join("\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const SAMPLE: &'static str = include_str!("../bin/day10/sample.txt");
+
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day10/sample.txt");
+
+    #[test]
+    fn test_against_register_trace() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(200) + 1, rng.index(40) + 1);
+            let program = Day10::parse(&generate(rng, size)).unwrap();
+
+            // the value of X during every cycle
+            let mut x = 1;
+            let mut trace = vec![];
+            for instruction in &program {
+                match instruction {
+                    Instruction::Noop => trace.push(x),
+                    Instruction::Addx(value) => {
+                        trace.extend([x, x]);
+                        x += value;
+                    }
+                }
+            }
+
+            let strength = (20..=220)
+                .step_by(40)
+                .map(|cycle| cycle as i32 * trace[cycle - 1])
+                .sum::<i32>();
+            let screen = trace[..SCREEN_SIZE]
+                .chunks(SCREEN_WIDTH)
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(col, x)| {
+                            if (col as i32 - x).abs() <= 1 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(signal_strength(&program), strength);
+            assert_eq!(draw(&program), screen);
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day10::parse(SAMPLE_INPUT).unwrap()), "13140");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day10>(Part::One);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day10::parse("noop\naddx 3\naddx\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.expected, "\"noop\" or \"addx <value>\"");
+
+        let err = Day10::parse("noop\naddx 3x\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a value to add, found \"3x\""
+        );
+
+        let err = Day10::parse("").err().unwrap();
+        assert_eq!(err.expected, "an instruction");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            p2(&Day10::parse(SAMPLE_INPUT).unwrap()),
+            r"
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day10>(Part::Two);
+    }
+}
@@ -0,0 +1,395 @@
+//! Day 11: Monkey in the Middle.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub enum Operand {
+    Val(i64),
+    Old,
+}
+
+impl Operand {
+    pub fn get<'a>(&'a self, old: &'a i64) -> &'a i64 {
+        match self {
+            Operand::Val(val) => val,
+            Operand::Old => old,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(Operand),
+    Mul(Operand),
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it does not fit in an `i64`.
+    pub fn compute(&self, old: &i64) -> Option<i64> {
+        match self {
+            Operation::Add(operand) => old.checked_add(*operand.get(old)),
+            Operation::Mul(operand) => old.checked_mul(*operand.get(old)),
+        }
+    }
+}
+
+/// A monkey holding worry levels, which it throws to `throw_true` when
+/// they are divisible by `test` and to `throw_false` otherwise.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<i64>,
+    pub op: Operation,
+    pub test: i64,
+    pub throw_true: usize,
+    pub throw_false: usize,
+    /// The number of items inspected so far.
+    pub inspected: usize,
+}
+
+impl Monkey {
+    /// Reads every monkey, checking that each throws only to monkeys that
+    /// are in the notes.
+    pub fn parse_input(input: &str) -> Result<Vec<Self>, ParseError> {
+        let blocks = parse::blocks(input);
+        let monkeys = blocks
+            .iter()
+            .map(|block| Self::parse_block(block))
+            .collect::<Result<Vec<_>, _>>()?;
+        if monkeys.is_empty() {
+            return Err(ParseError::end_of_input(input, "a monkey"));
+        }
+
+        for (block, monkey) in blocks.iter().zip(&monkeys) {
+            // the throws are always the last two lines of a monkey's notes
+            let throws = &block[block.len() - 2..];
+            for (line, target) in throws.iter().zip([monkey.throw_true, monkey.throw_false]) {
+                if target >= monkeys.len() {
+                    let number = line.text.rsplit(' ').next().unwrap();
+                    return Err(
+                        line.error(number, format!("a monkey number below {}", monkeys.len()))
+                    );
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    /// Reads one monkey's paragraph of notes.
+    pub fn parse_block(block: &[Line]) -> Result<Self, ParseError> {
+        let mut lines = block.iter();
+        let mut field = |prefix: &str| -> Result<(Line, &str), ParseError> {
+            let line = *lines.next().ok_or_else(|| ParseError {
+                line: block[block.len() - 1].number + 1,
+                column: 1,
+                expected: format!("{:?}", prefix),
+                found: "end of monkey".to_string(),
+            })?;
+            let text = line.text.trim_start();
+            let rest = text
+                .strip_prefix(prefix)
+                .ok_or_else(|| line.error(text, format!("{:?}", prefix)))?;
+            Ok((line, rest))
+        };
+
+        let (line, id) = field("Monkey ")?;
+        if !id.ends_with(':') {
+            return Err(line.error(&id[id.len()..], "\":\""));
+        }
+
+        let (line, items) = field("Starting items: ")?;
+        let items = items
+            .split(',')
+            .map(|val| line.parse(val.trim(), "a worry level"))
+            .collect::<Result<_, _>>()?;
+
+        let (line, operation) = field("Operation: new = old ")?;
+        let (operator, operand) = operation
+            .split_once(' ')
+            .ok_or_else(|| line.error(&operation[operation.len()..], "an operand"))?;
+        let operand = if operand == "old" {
+            Operand::Old
+        } else {
+            Operand::Val(line.parse(operand, "\"old\" or a number")?)
+        };
+        let op = match operator {
+            "+" => Operation::Add(operand),
+            "*" => Operation::Mul(operand),
+            _ => return Err(line.error(operator, "\"+\" or \"*\"")),
+        };
+
+        let (line, divisor) = field("Test: divisible by ")?;
+        let test = line.parse(divisor, "a divisor")?;
+        if test <= 0 {
+            return Err(line.error(divisor, "a positive divisor"));
+        }
+
+        let (line, throw_true) = field("If true: throw to monkey ")?;
+        let throw_true = line.parse(throw_true, "a monkey number")?;
+
+        let (line, throw_false) = field("If false: throw to monkey ")?;
+        let throw_false = line.parse(throw_false, "a monkey number")?;
+
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(extra.text, "a blank line"));
+        }
+
+        Ok(Self {
+            items,
+            op,
+            test,
+            throw_true,
+            throw_false,
+            inspected: 0,
+        })
+    }
+}
+
+/// The monkey business after `rounds`, or `None` if a worry level overflows.
+/// `worry` keeps the worry levels in check after every inspection.
+pub fn solve(mut monkeys: Vec<Monkey>, rounds: usize, worry: impl Fn(i64) -> i64) -> Option<usize> {
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let monkey = &mut monkeys[i];
+            monkey.inspected += monkey.items.len();
+
+            let thrown = std::mem::take(&mut monkey.items)
+                .into_iter()
+                .map(|val| {
+                    let val = worry(monkey.op.compute(&val)?);
+                    let target = if val % monkey.test == 0 {
+                        monkey.throw_true
+                    } else {
+                        monkey.throw_false
+                    };
+                    Some((target, val))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            thrown.into_iter().for_each(|(target, val)| {
+                monkeys[target].items.push(val);
+            });
+        }
+    }
+
+    monkeys.sort_by(|a, b| a.inspected.cmp(&b.inspected).reverse());
+    Some(
+        monkeys
+            .into_iter()
+            .take(2)
+            .map(|monkey| monkey.inspected)
+            .product::<usize>(),
+    )
+}
+
+fn overflow(what: &str) -> Answer {
+    Answer::Unsolved(format!("{} does not fit in an i64", what))
+}
+
+fn p1(monkeys: &[Monkey]) -> Answer {
+    match solve(monkeys.to_vec(), 20, |val| val / 3) {
+        Some(business) => business.into(),
+        None => overflow("a worry level"),
+    }
+}
+
+fn p2(monkeys: &[Monkey]) -> Answer {
+    let Some(prime) = monkeys
+        .iter()
+        .try_fold(1i64, |product, monkey| product.checked_mul(monkey.test))
+    else {
+        return overflow("the product of the divisors");
+    };
+    match solve(monkeys.to_vec(), 10000, |val| val % prime) {
+        Some(business) => business.into(),
+        None => overflow("a worry level"),
+    }
+}
+
+/// `size.width` monkeys holding up to `size.height` items each. There are
+/// at most 9 monkeys, each testing a different prime, so that the product
+/// that part 2 works modulo stays small enough to square.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let count = size.width.clamp(2, primes.len());
+
+    let mut monkeys = (0..count)
+        .map(|i| {
+            let other = |rng: &mut Rng| (i + 1 + rng.index(count - 1)) % count;
+            let throw_true = other(rng);
+            let throw_false = loop {
+                let target = other(rng);
+                if target != throw_true || count == 2 {
+                    break target;
+                }
+            };
+            Monkey {
+                items: (0..rng.range(1..=size.height as i64))
+                    .map(|_| rng.range(50..=99))
+                    .collect(),
+                op: match rng.below(5) {
+                    0 => Operation::Mul(Operand::Old),
+                    1 | 2 => Operation::Mul(Operand::Val(rng.range(2..=19))),
+                    _ => Operation::Add(Operand::Val(rng.range(1..=8))),
+                },
+                test: primes[i],
+                throw_true,
+                throw_false,
+                inspected: 0,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // nothing keeps the worry levels of part 1 in check, so turn
+    // multiplications into additions until they fit
+    while solve(monkeys.clone(), 20, |val| val / 3).is_none() {
+        let multipliers = (0..count)
+            .filter(|i| matches!(monkeys[*i].op, Operation::Mul(..)))
+            .collect::<Vec<_>>();
+        monkeys[*rng.pick(&multipliers)].op = Operation::Add(Operand::Val(rng.range(1..=8)));
+    }
+
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, monkey)| {
+            let (operator, operand) = match &monkey.op {
+                Operation::Add(operand) => ('+', operand),
+                Operation::Mul(operand) => ('*', operand),
+            };
+            let operand = match operand {
+                Operand::Val(val) => val.to_string(),
+                Operand::Old => "old".to_string(),
+            };
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}",
+                i,
+                monkey.items.iter().join(", "),
+                operator,
+                operand,
+                monkey.test,
+                monkey.throw_true,
+                monkey.throw_false
+            )
+        })
+        .join("\n\n")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const SAMPLE: &'static str = include_str!("../bin/day11/sample.txt");
+
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Monkey::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day11/sample.txt");
+
+    #[test]
+    fn test_worry_modulo_any_common_multiple() {
+        rng::check(30, |rng| {
+            let size = Size::new(rng.index(9) + 1, rng.index(5) + 1);
+            let monkeys = Day11::parse(&generate(rng, size)).unwrap();
+            let product = monkeys.iter().map(|monkey| monkey.test).product::<i64>();
+
+            // every test only sees the worry level modulo its divisor, so
+            // keeping levels modulo any multiple of all of them is the same
+            let business = |modulus: i64| solve(monkeys.clone(), 500, |val| val % modulus);
+            assert!(business(product).is_some());
+            assert_eq!(business(product), business(2 * product));
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day11::parse(SAMPLE_INPUT).unwrap()), "10605");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day11>(Part::One);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day11::parse(&SAMPLE_INPUT.replace("old * 19", "old / 19"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (3, 24));
+        assert_eq!(err.expected, "\"+\" or \"*\"");
+
+        let err = Day11::parse(&SAMPLE_INPUT.replace("    If false: throw to monkey 3\n", ""))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(err.found, "end of monkey");
+
+        let err = Day11::parse(
+            &SAMPLE_INPUT.replace("If true: throw to monkey 2", "If true: throw to monkey 4"),
+        )
+        .err()
+        .unwrap();
+        assert_eq!((err.line, err.column), (5, 30));
+        assert_eq!(err.expected, "a monkey number below 4");
+        assert_eq!(err.found, "\"4\"");
+
+        let err = Day11::parse(&SAMPLE_INPUT.replace("divisible by 23", "divisible by 0"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (4, 22));
+        assert_eq!(err.expected, "a positive divisor");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = SAMPLE_INPUT.replace("old * 19", "old * 9223372036854775807");
+        let monkeys = Day11::parse(&input).unwrap();
+        assert_eq!(
+            p1(&monkeys).unsolved(),
+            Some("a worry level does not fit in an i64")
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day11::parse(SAMPLE_INPUT).unwrap()), "2713310158");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day11>(Part::Two);
+    }
+}
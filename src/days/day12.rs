@@ -0,0 +1,205 @@
+//! Day 12: Hill Climbing Algorithm.
+
+use crate::{
+    generate::Size, grid::Grid, parse::ParseError, point::Point2, rng::Rng, search, Answer,
+    Solution,
+};
+
+/// The heightmap, with `S` and `E` replaced by heights 0 (`a`) and 25 (`z`).
+#[derive(Debug)]
+pub struct World {
+    pub start: Point2<usize>,
+    pub end: Point2<usize>,
+    pub heights: Grid<i32>,
+}
+
+/// Where the climb to `E` may begin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceToConsider {
+    OnlyStart,
+    AllLowest,
+}
+
+impl World {
+    pub fn parse_input(input: &str) -> Result<Self, ParseError> {
+        fn find_special_position(
+            input: &str,
+            grid: &Grid<char>,
+            ch: char,
+        ) -> Result<Point2<usize>, ParseError> {
+            grid.iter()
+                .find(|(_, val)| **val == ch)
+                .map(|(pos, _)| pos)
+                .ok_or_else(|| ParseError::end_of_input(input, format!("a square marked {}", ch)))
+        }
+
+        let grid = Grid::parse(input, "a height (a-z), S or E", |ch| {
+            (ch.is_ascii_lowercase() || ch == 'S' || ch == 'E').then_some(ch)
+        })?;
+
+        let start = find_special_position(input, &grid, 'S')?;
+        let end = find_special_position(input, &grid, 'E')?;
+        let heights = grid.map(|ch| match ch {
+            'S' => 0,
+            'E' => 25,
+            _ => (*ch as u32 - 'a' as u32) as i32,
+        });
+
+        Ok(Self {
+            start,
+            end,
+            heights,
+        })
+    }
+
+    /// The fewest steps to `E`, climbing at most one unit of height at a
+    /// time, if `E` can be reached.
+    pub fn find_shortest(&self, source_to_consider: &SourceToConsider) -> Option<usize> {
+        let sources = match source_to_consider {
+            SourceToConsider::OnlyStart => vec![self.start],
+            SourceToConsider::AllLowest => self
+                .heights
+                .iter()
+                .filter(|(_, cell)| **cell == 0)
+                .map(|(coord, _)| coord)
+                .collect(),
+        };
+
+        search::bfs(
+            sources,
+            |current| {
+                self.heights
+                    .neighbours4(*current)
+                    .filter(|coord| self.heights[*coord] - self.heights[*current] <= 1)
+                    .collect::<Vec<_>>()
+            },
+            |coord| *coord == self.end,
+        )
+        .map(|path| path.cost)
+    }
+}
+
+fn shortest(world: &World, source_to_consider: &SourceToConsider) -> Answer {
+    match world.find_shortest(source_to_consider) {
+        Some(steps) => steps.into(),
+        None => Answer::Unsolved("E cannot be reached".to_string()),
+    }
+}
+
+fn p1(world: &World) -> Answer {
+    shortest(world, &SourceToConsider::OnlyStart)
+}
+
+fn p2(world: &World) -> Answer {
+    shortest(world, &SourceToConsider::AllLowest)
+}
+
+/// A `size.width`x`size.height` heightmap of random heights, with a
+/// staircase from S in the top left corner up to E in the bottom right.
+/// The map is widened if needed to fit every step from `a` to `z`.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let height = size.height;
+    let width = size.width.max(27_usize.saturating_sub(height));
+    let mut map = Grid::from_rows(
+        (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| (b'a' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect(),
+    );
+
+    // walk right and down, never climbing more than one step at a time
+    let steps = width + height - 2;
+    let mut position = Point2::new(0, 0);
+    for step in 0..=steps {
+        map[position] = (b'a' + (step * 25 / steps) as u8) as char;
+        if position.x + 1 < width && (position.y + 1 == height || rng.chance(0.5)) {
+            position.x += 1;
+        } else {
+            position.y += 1;
+        }
+    }
+    map[Point2::new(0, 0)] = 'S';
+    map[Point2::new(width - 1, height - 1)] = 'E';
+
+    map.to_string()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+    const SAMPLE: &'static str = include_str!("../bin/day12/sample.txt");
+
+    type Input<'a> = World;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        World::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day12/sample.txt");
+
+    #[test]
+    fn test_any_lowest_start_is_no_farther() {
+        rng::check(50, |rng| {
+            let size = Size::new(rng.index(30) + 1, rng.index(10) + 1);
+            let world = Day12::parse(&generate(rng, size)).unwrap();
+            let from_start = world.find_shortest(&SourceToConsider::OnlyStart).unwrap();
+            let from_lowest = world.find_shortest(&SourceToConsider::AllLowest).unwrap();
+
+            // S is one of the lowest squares, and every step climbs one
+            // unit at most, all the way from a to z
+            assert!(from_lowest <= from_start);
+            assert!(from_start >= world.start.manhattan(world.end));
+            assert!(from_lowest >= 25);
+        });
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let world = Day12::parse("SbE").unwrap();
+        assert_eq!(p1(&world).unsolved(), Some("E cannot be reached"));
+        assert_eq!(p2(&world).unsolved(), Some("E cannot be reached"));
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day12::parse(SAMPLE_INPUT).unwrap()), "31");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day12>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day12::parse(SAMPLE_INPUT).unwrap()), "29");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day12>(Part::Two);
+    }
+}
@@ -0,0 +1,245 @@
+//! Day 13: Distress Signal. Packets are kept as JSON values, which
+//! [`parse_packet`] checks to hold only integers and lists.
+
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// Compares two packets, where `Less` means that they are in the right
+/// order. Panics on values other than integers and lists.
+pub fn determine_order(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            left.as_i64().unwrap().cmp(&right.as_i64().unwrap())
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            if let Some(result) = left.iter().zip(right.iter()).find_map(|(left, right)| {
+                let result = determine_order(left, right);
+                if matches!(result, Ordering::Equal) {
+                    None
+                } else {
+                    Some(result)
+                }
+            }) {
+                result
+            } else {
+                left.len().cmp(&right.len())
+            }
+        }
+        (Value::Number(..), Value::Array(..)) => {
+            determine_order(&Value::Array(vec![left.clone()]), right)
+        }
+        (Value::Array(..), Value::Number(..)) => {
+            determine_order(left, &Value::Array(vec![right.clone()]))
+        }
+        _ => {
+            panic!("Combination is not supported.");
+        }
+    }
+}
+
+pub fn parse_packet(line: &Line) -> Result<Value, ParseError> {
+    fn is_packet(value: &Value) -> bool {
+        match value {
+            Value::Number(number) => number.is_i64(),
+            Value::Array(values) => values.iter().all(is_packet),
+            _ => false,
+        }
+    }
+
+    let packet = serde_json::from_str::<Value>(line.text).map_err(|err| {
+        let offset = line.text.len().min(err.column().saturating_sub(1));
+        line.error(&line.text[offset..], "a packet")
+    })?;
+
+    match packet {
+        Value::Array(..) if is_packet(&packet) => Ok(packet),
+        _ => Err(line.error(line.text, "a list of integers and lists")),
+    }
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<(Value, Value)>, ParseError> {
+    let pairs = parse::blocks(input)
+        .into_iter()
+        .map(|block| match block.as_slice() {
+            [left, right] => Ok((parse_packet(left)?, parse_packet(right)?)),
+            [_] => Err(ParseError {
+                line: block[0].number + 1,
+                column: 1,
+                expected: "a second packet".to_string(),
+                found: "a blank line".to_string(),
+            }),
+            _ => Err(block[2].error(block[2].text, "a blank line")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if pairs.is_empty() {
+        return Err(ParseError::end_of_input(input, "a pair of packets"));
+    }
+    Ok(pairs)
+}
+
+fn p1(pairs: &[(Value, Value)]) -> Answer {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| matches!(determine_order(left, right), Ordering::Less))
+        .map(|(index, _)| index + 1)
+        .sum::<usize>()
+        .into()
+}
+
+fn p2(pairs: &[(Value, Value)]) -> Answer {
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect::<Vec<_>>();
+
+    let decoders = [
+        serde_json::from_str::<Value>("[[2]]").unwrap(),
+        serde_json::from_str::<Value>("[[6]]").unwrap(),
+    ];
+    packets.extend(decoders.clone());
+    packets.sort_by(determine_order);
+
+    packets
+        .into_iter()
+        .enumerate()
+        .filter(|(_, packet)| packet == &decoders[0] || packet == &decoders[1])
+        .map(|(index, _)| index + 1)
+        .product::<usize>()
+        .into()
+}
+
+/// `size.width` pairs of packets, with lists nested at most `size.height`
+/// deep.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items = (0..rng.range(0..=4))
+            .map(|_| {
+                if depth > 1 && rng.chance(0.3) {
+                    packet(rng, depth - 1)
+                } else {
+                    rng.range(0..=10).to_string()
+                }
+            })
+            .join(",");
+        format!("[{}]", items)
+    }
+
+    (0..size.width)
+        .map(|_| {
+            let left = packet(rng, size.height);
+            format!("{}\n{}", left, packet(rng, size.height))
+        })
+        .join("\n\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+    const SAMPLE: &'static str = include_str!("../bin/day13/sample.txt");
+
+    type Input<'a> = Vec<(Value, Value)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day13/sample.txt");
+
+    #[test]
+    fn test_order_is_consistent() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(10) + 1, rng.index(4) + 1);
+            let pairs = Day13::parse(&generate(rng, size)).unwrap();
+            let packets = pairs
+                .iter()
+                .flat_map(|(left, right)| [left, right])
+                .collect::<Vec<_>>();
+
+            for left in &packets {
+                assert_eq!(determine_order(left, left), Ordering::Equal);
+                for right in &packets {
+                    assert_eq!(
+                        determine_order(left, right),
+                        determine_order(right, left).reverse(),
+                        "{} {}",
+                        left,
+                        right
+                    );
+                }
+            }
+
+            // the dividers go after every packet that is not ordered after
+            // them, unless a packet is a divider itself
+            let dividers =
+                ["[[2]]", "[[6]]"].map(|divider| serde_json::from_str::<Value>(divider).unwrap());
+            if packets.iter().any(|packet| dividers.contains(packet)) {
+                return;
+            }
+            let before = |divider: &Value| {
+                packets
+                    .iter()
+                    .filter(|packet| determine_order(packet, divider) != Ordering::Greater)
+                    .count()
+            };
+            let key = (before(&dividers[0]) + 1) * (before(&dividers[1]) + 2);
+            assert_eq!(p2(&pairs), key.to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day13::parse("").err().unwrap();
+        assert_eq!(err.expected, "a pair of packets");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day13::parse(SAMPLE_INPUT).unwrap()), "13");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day13>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day13::parse(SAMPLE_INPUT).unwrap()), "140");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day13>(Part::Two);
+    }
+}
@@ -0,0 +1,280 @@
+//! Day 14: Regolith Reservoir.
+
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    point::Point2,
+    rng::Rng,
+    visualize::{plot, Recorder},
+    Answer, Solution,
+};
+
+/// Where the sand pours in from.
+pub const SOURCE: Point2 = Point2::new(500, 0);
+
+/// Where a grain of sand tries to go, in order of preference.
+const FALLS: [Point2; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+/// The cave, with the rocks and the sand that has come to rest so far.
+#[derive(Debug, Clone)]
+pub struct World {
+    rocks: HashSet<Point2>,
+    abyss: i32,
+    original_rocks_count: usize,
+}
+
+impl World {
+    /// Every point covered by a path of rock.
+    pub fn parse_path(line: Line) -> Result<Vec<Point2>, ParseError> {
+        let points = line
+            .text
+            .split(" -> ")
+            .map(|point| {
+                let (a, b) = point
+                    .split_once(',')
+                    .ok_or_else(|| line.error(point, "a point \"x,y\""))?;
+                Ok((
+                    Point2::new(
+                        line.parse::<i32>(a, "a coordinate")?,
+                        line.parse::<i32>(b, "a coordinate")?,
+                    ),
+                    point,
+                ))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        points
+            .into_iter()
+            .tuple_windows()
+            .map(|((a, _), (b, fragment))| {
+                if a.x == b.x || a.y == b.y {
+                    let step = (b - a).signum();
+                    Ok((0..=a.chebyshev(b))
+                        .map(|i| a + step * i)
+                        .collect::<Vec<_>>())
+                } else {
+                    Err(line.error(fragment, "a point in line with the previous one"))
+                }
+            })
+            .flatten_ok()
+            .collect()
+    }
+
+    pub fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut rocks = HashSet::new();
+        for line in parse::lines(input) {
+            rocks.extend(Self::parse_path(line)?);
+        }
+        let abyss = rocks
+            .iter()
+            .map(|point| point.y)
+            .max()
+            .ok_or_else(|| ParseError::end_of_input(input, "a rock path"))?
+            + 1;
+        let original_rocks_count = rocks.len();
+
+        Ok(Self {
+            rocks,
+            abyss,
+            original_rocks_count,
+        })
+    }
+
+    /// Drops a grain of sand, returning `false` once it falls into the
+    /// abyss below the lowest rock, or the source is blocked.
+    pub fn add_sand(&mut self) -> bool {
+        let mut position = SOURCE;
+
+        if self.rocks.contains(&position) {
+            return false;
+        }
+
+        while position.y < self.abyss {
+            if let Some(pos) = FALLS
+                .into_iter()
+                .map(|fall| position + fall)
+                .find(|pos| !self.rocks.contains(pos))
+            {
+                position = pos;
+            } else {
+                break;
+            }
+        }
+
+        if position.y < self.abyss {
+            self.rocks.insert(position);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Drops a grain of sand onto the floor just below the lowest rock,
+    /// returning `false` once the source is blocked.
+    pub fn add_sand_p2(&mut self) -> bool {
+        let mut position = SOURCE;
+
+        if self.rocks.contains(&position) {
+            return false;
+        }
+
+        while position.y < self.abyss {
+            if let Some(pos) = FALLS
+                .into_iter()
+                .map(|fall| position + fall)
+                .find(|pos| !self.rocks.contains(pos))
+            {
+                position = pos;
+            } else {
+                break;
+            }
+        }
+
+        self.rocks.insert(position);
+        true
+    }
+
+    /// The grains of sand that have come to rest.
+    pub fn get_total_sands(&self) -> i32 {
+        self.rocks.len() as i32 - self.original_rocks_count as i32
+    }
+}
+
+fn p1(world: &World) -> Answer {
+    let mut world = world.clone();
+    while world.add_sand() {}
+    world.get_total_sands().into()
+}
+
+fn p2(world: &World) -> Answer {
+    let mut world = world.clone();
+    while world.add_sand_p2() {}
+    world.get_total_sands().into()
+}
+
+/// Pours sand as in part 1, drawing a frame for every grain that comes to
+/// rest.
+fn visualize(world: &World, recorder: &mut Recorder) {
+    let mut world = world.clone();
+    let rocks = world.rocks.clone();
+    let frame = |world: &World| {
+        plot(
+            world
+                .rocks
+                .iter()
+                .map(|point| (*point, if rocks.contains(point) { '#' } else { 'o' }))
+                .chain([(SOURCE, '+')]),
+            '.',
+        )
+    };
+
+    let mut more = recorder.record(&frame(&world));
+    while more && world.add_sand() {
+        more = recorder.record(&frame(&world));
+    }
+}
+
+/// `size.width` rock paths under the source, going sideways or down but no
+/// deeper than `size.height`.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let depth = size.height as i64;
+    (0..size.width)
+        .map(|_| {
+            let mut point = Point2::new(
+                rng.range(500 - depth..=500 + depth) as i32,
+                rng.range(1..=depth) as i32,
+            );
+            let mut path = vec![point];
+            for segment in 0..rng.range(1..=4) {
+                point += if segment % 2 == 0 {
+                    Point2::new(rng.range(-6..=6) as i32, 0)
+                } else {
+                    let room = depth - point.y as i64;
+                    Point2::new(0, rng.range(0..=room.min(6)) as i32)
+                };
+                path.push(point);
+            }
+            path.iter()
+                .map(|point| format!("{},{}", point.x, point.y))
+                .join(" -> ")
+        })
+        .join("\n")
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+    const SAMPLE: &'static str = include_str!("../bin/day14/sample.txt");
+
+    type Input<'a> = World;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        World::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn visualize(input: &Self::Input<'_>, recorder: &mut Recorder) -> bool {
+        visualize(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day14/sample.txt");
+
+    #[test]
+    fn test_p2_not_less_than_p1() {
+        let count = |answer: Answer| answer.to_string().parse::<i32>().unwrap();
+
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(6) + 1, 20);
+            let world = Day14::parse(&generate(rng, size)).unwrap();
+            let (p1, p2) = (count(p1(&world)), count(p2(&world)));
+
+            // the floor only ever stops sand that would have fallen forever,
+            // and the pile under the source is a triangle at most
+            assert!(p1 <= p2, "{} > {}", p1, p2);
+            assert!(p2 <= (world.abyss + 1).pow(2));
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day14::parse(SAMPLE_INPUT).unwrap()), "24");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day14>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day14::parse(SAMPLE_INPUT).unwrap()), "93");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day14>(Part::Two);
+    }
+}
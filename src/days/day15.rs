@@ -0,0 +1,353 @@
+//! Day 15: Beacon Exclusion Zone.
+
+use itertools::Itertools;
+use std::ops::RangeInclusive;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    point::Point2,
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// The row and search area of the sample, and those of the actual puzzle.
+const SAMPLE_SCALE: (i64, i64) = (10, 20);
+const ACTUAL_SCALE: (i64, i64) = (2_000_000, 4_000_000);
+
+/// How far from the origin sensors of an input at the sample's scale can
+/// be. Actual inputs have their sensors hundreds of thousands of units
+/// apart, so there is no mistaking one for the other.
+pub const SAMPLE_EXTENT: i64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sensor {
+    pub position: Point2<i64>,
+    /// The closest beacon, which no other beacon is as close as.
+    pub beacon: Point2<i64>,
+}
+
+impl Sensor {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let point = |text: &str| -> Result<Point2<i64>, ParseError> {
+            let (x, y) = text
+                .split_once(", ")
+                .ok_or_else(|| line.error(&text[text.len()..], "\", y=\""))?;
+            let x = x
+                .strip_prefix("x=")
+                .ok_or_else(|| line.error(x, "\"x=\""))?;
+            let y = y
+                .strip_prefix("y=")
+                .ok_or_else(|| line.error(y, "\"y=\""))?;
+            Ok(Point2::new(
+                line.parse(x, "a coordinate")?,
+                line.parse(y, "a coordinate")?,
+            ))
+        };
+
+        let sensor = line.strip_prefix("Sensor at ")?;
+        let (sensor, beacon) = sensor
+            .split_once(": closest beacon is at ")
+            .ok_or_else(|| {
+                line.error(
+                    &sensor[sensor.len()..],
+                    "\": closest beacon is at <position>\"",
+                )
+            })?;
+
+        Ok(Self {
+            position: point(sensor)?,
+            beacon: point(beacon)?,
+        })
+    }
+
+    pub fn radius(&self) -> i64 {
+        self.position.manhattan(self.beacon)
+    }
+
+    /// Whether `point` is at least as close as the beacon, and so cannot
+    /// hold an unknown beacon.
+    pub fn covers(&self, point: Point2<i64>) -> bool {
+        self.position.manhattan(point) <= self.radius()
+    }
+
+    /// The positions this sensor covers along row `y`, if any.
+    pub fn row_coverage(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let reach = self.radius() - (y - self.position.y).abs();
+        (reach >= 0).then(|| self.position.x - reach..=self.position.x + reach)
+    }
+}
+
+/// The sensors, and where the puzzle asks to look for the beacon.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub sensors: Vec<Sensor>,
+    /// The row that part 1 counts the covered positions of.
+    pub row: i64,
+    /// Part 2 looks for the beacon within `0..=limit` on both axes.
+    pub limit: i64,
+}
+
+impl Report {
+    /// Reads the sensors. Nothing in the input says which row and search
+    /// area to use, so the sample's are picked when every sensor is within
+    /// [`SAMPLE_EXTENT`] of the origin on both axes, and the actual
+    /// puzzle's otherwise.
+    pub fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let sensors = parse::lines(input)
+            .map(Sensor::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if sensors.is_empty() {
+            return Err(ParseError::end_of_input(input, "a sensor"));
+        }
+
+        let small = sensors
+            .iter()
+            .all(|sensor| sensor.position.x.abs().max(sensor.position.y.abs()) <= SAMPLE_EXTENT);
+        let (row, limit) = if small { SAMPLE_SCALE } else { ACTUAL_SCALE };
+
+        Ok(Self {
+            sensors,
+            row,
+            limit,
+        })
+    }
+}
+
+/// The positions covered along row `y`, as sorted and disjoint intervals.
+pub fn merge_row(sensors: &[Sensor], y: i64) -> Vec<RangeInclusive<i64>> {
+    let mut merged: Vec<RangeInclusive<i64>> = vec![];
+
+    for range in sensors
+        .iter()
+        .filter_map(|sensor| sensor.row_coverage(y))
+        .sorted_by_key(|range| *range.start())
+    {
+        match merged.last_mut() {
+            // touching intervals merge too, as positions are whole numbers
+            Some(last) if *range.start() <= last.end() + 1 => {
+                *last = *last.start()..=*last.end().max(range.end());
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// The positions along row `y` that cannot hold a beacon, leaving out the
+/// beacons already known to be there.
+pub fn count_excluded(sensors: &[Sensor], y: i64) -> usize {
+    let covered = merge_row(sensors, y)
+        .iter()
+        .map(|range| (range.end() - range.start() + 1) as usize)
+        .sum::<usize>();
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.y == y)
+        .unique()
+        .count();
+
+    covered - beacons
+}
+
+/// A position within `0..=limit` on both axes that no sensor covers.
+///
+/// Rather than scanning every row, the search area is split into quarters
+/// until each piece is either covered by a single sensor or is a lone
+/// uncovered position. A sensor's diamond is convex, so it covers a square
+/// as soon as it covers all four corners.
+pub fn find_distress_beacon(sensors: &[Sensor], limit: i64) -> Option<Point2<i64>> {
+    let mut squares = vec![(Point2::new(0, 0), Point2::new(limit, limit))];
+
+    while let Some((min, max)) = squares.pop() {
+        let corners = [
+            min,
+            Point2::new(min.x, max.y),
+            Point2::new(max.x, min.y),
+            max,
+        ];
+        if sensors
+            .iter()
+            .any(|sensor| corners.iter().all(|corner| sensor.covers(*corner)))
+        {
+            continue;
+        }
+        if min == max {
+            return Some(min);
+        }
+
+        let mid = Point2::new(min.x + (max.x - min.x) / 2, min.y + (max.y - min.y) / 2);
+        for (x0, x1) in [(min.x, mid.x), (mid.x + 1, max.x)] {
+            for (y0, y1) in [(min.y, mid.y), (mid.y + 1, max.y)] {
+                if x0 <= x1 && y0 <= y1 {
+                    squares.push((Point2::new(x0, y0), Point2::new(x1, y1)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn tuning_frequency(beacon: Point2<i64>) -> i64 {
+    beacon.x * 4_000_000 + beacon.y
+}
+
+fn p1(report: &Report) -> Answer {
+    count_excluded(&report.sensors, report.row).into()
+}
+
+fn p2(report: &Report) -> Answer {
+    match find_distress_beacon(&report.sensors, report.limit) {
+        Some(beacon) => tuning_frequency(beacon).into(),
+        None => Answer::Unsolved(format!(
+            "no distress beacon: every position within 0..={} is covered",
+            report.limit
+        )),
+    }
+}
+
+/// `size.width` sensors up to `size.height` away from the sample's search
+/// area, each with a beacon closer than one hidden spot in the area, so
+/// that part 2 always has an answer. Sensors stay within [`SAMPLE_EXTENT`],
+/// so that the input is read at the sample's scale.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let (_, limit) = SAMPLE_SCALE;
+    let spread = (size.height as i64).min(SAMPLE_EXTENT - limit);
+    let hidden = Point2::new(rng.range(0..=limit), rng.range(0..=limit));
+
+    (0..size.width)
+        .map(|_| {
+            let sensor = loop {
+                let sensor = Point2::new(
+                    rng.range(-spread..=limit + spread),
+                    rng.range(-spread..=limit + spread),
+                );
+                if sensor.manhattan(hidden) >= 2 {
+                    break sensor;
+                }
+            };
+
+            let radius = rng.range(1..=sensor.manhattan(hidden) - 1);
+            let dx = rng.range(-radius..=radius);
+            let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            let beacon = sensor + Point2::new(dx, dy);
+
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.x, sensor.y, beacon.x, beacon.y
+            )
+        })
+        .join("\n")
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+    const SAMPLE: &'static str = include_str!("../bin/day15/sample.txt");
+
+    type Input<'a> = Report;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Report::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day15/sample.txt");
+
+    #[test]
+    fn test_merge_row() {
+        let report = Day15::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(merge_row(&report.sensors, 10), vec![-2..=24]);
+        assert_eq!(merge_row(&report.sensors, 11), vec![-3..=13, 15..=25]);
+    }
+
+    #[test]
+    fn test_generated_reports() {
+        rng::check(50, |rng| {
+            let size = Size::new(rng.index(12) + 1, 10);
+            let report = Day15::parse(&generate(rng, size)).unwrap();
+            let sensors = &report.sensors;
+
+            // against a plain scan, wide enough for any sensor to reach
+            let excluded = (-100..=100)
+                .map(|x| Point2::new(x, report.row))
+                .filter(|point| {
+                    sensors.iter().any(|sensor| sensor.covers(*point))
+                        && sensors.iter().all(|sensor| sensor.beacon != *point)
+                })
+                .count();
+            assert_eq!(count_excluded(sensors, report.row), excluded);
+
+            let beacon = find_distress_beacon(sensors, report.limit).unwrap();
+            assert!((0..=report.limit).contains(&beacon.x));
+            assert!((0..=report.limit).contains(&beacon.y));
+            assert!(sensors.iter().all(|sensor| !sensor.covers(beacon)));
+        });
+    }
+
+    #[test]
+    fn test_scale() {
+        let report = Day15::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!((report.row, report.limit), SAMPLE_SCALE);
+
+        let far = "Sensor at x=1001, y=0: closest beacon is at x=1002, y=0";
+        let report = Day15::parse(far).unwrap();
+        assert_eq!((report.row, report.limit), ACTUAL_SCALE);
+
+        // generated inputs are never mistaken for actual ones
+        let input = generate(&mut Rng::new(0), Size::new(50, 10_000));
+        assert_eq!(Day15::parse(&input).unwrap().limit, SAMPLE_SCALE.1);
+    }
+
+    #[test]
+    fn test_p2_without_beacon() {
+        // a single sensor covering the whole search area
+        let report = Day15::parse("Sensor at x=10, y=10: closest beacon is at x=30, y=10").unwrap();
+        assert_eq!(
+            p2(&report).unsolved(),
+            Some("no distress beacon: every position within 0..=20 is covered")
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day15::parse(SAMPLE_INPUT).unwrap()), "26");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day15>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day15::parse(SAMPLE_INPUT).unwrap()), "56000011");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day15>(Part::Two);
+    }
+}
@@ -0,0 +1,455 @@
+//! Day 16: Proboscidea Volcanium.
+
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// More valves with a flow rate than this would not fit the table that
+/// [`best_pressures`] keeps for every set of open valves.
+const MAX_USEFUL_VALVES: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve<'a> {
+    pub name: &'a str,
+    /// The pressure released every minute once the valve is open.
+    pub flow: u32,
+    pub tunnels: Vec<&'a str>,
+}
+
+impl<'a> Valve<'a> {
+    pub fn parse(line: Line<'a>) -> Result<Self, ParseError> {
+        let rest = line.strip_prefix("Valve ")?;
+        let (name, rest) = rest
+            .split_once(" has flow rate=")
+            .ok_or_else(|| line.error(&rest[rest.len()..], "\" has flow rate=\""))?;
+        let (flow, tunnels) = rest
+            .split_once("; ")
+            .ok_or_else(|| line.error(&rest[rest.len()..], "\"; \""))?;
+        let tunnels = tunnels
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| line.error(tunnels, "\"tunnels lead to valves\""))?;
+
+        Ok(Self {
+            name,
+            flow: line.parse(flow, "a flow rate")?,
+            tunnels: tunnels.split(", ").collect(),
+        })
+    }
+}
+
+/// The valves worth opening, with how many minutes it takes to walk
+/// between them. Valves without flow are only ever walked through, so they
+/// are left out, except for the starting valve `AA`.
+#[derive(Debug, Clone)]
+pub struct Network {
+    /// The flow rate of each valve worth opening.
+    pub flows: Vec<u32>,
+    /// The shortest walks between the valves above, followed by `AA` if
+    /// it has no flow of its own.
+    pub distances: Vec<Vec<u32>>,
+    /// Where `AA` is in `distances`.
+    pub start: usize,
+}
+
+impl Network {
+    /// Reads the valves, checking that `AA` is listed, that every tunnel
+    /// leads to a listed valve, and that there are at most
+    /// [`MAX_USEFUL_VALVES`] valves with a flow rate.
+    pub fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut valves = vec![];
+        let mut lines = vec![];
+        let mut useful = 0;
+
+        for line in parse::lines(input) {
+            let valve = Valve::parse(line)?;
+            if valves.iter().any(|other: &Valve| other.name == valve.name) {
+                return Err(line.error(valve.name, "a valve that was not listed before"));
+            }
+            if valve.flow > 0 {
+                useful += 1;
+                if useful > MAX_USEFUL_VALVES {
+                    return Err(line.error(
+                        line.text,
+                        format!("at most {} valves with a flow rate", MAX_USEFUL_VALVES),
+                    ));
+                }
+            }
+            valves.push(valve);
+            lines.push(line);
+        }
+
+        for (line, valve) in lines.iter().zip(&valves) {
+            if let Some(tunnel) = valve
+                .tunnels
+                .iter()
+                .find(|tunnel| valves.iter().all(|valve| valve.name != **tunnel))
+            {
+                return Err(line.error(tunnel, "a valve that is listed somewhere"));
+            }
+        }
+        if valves.iter().all(|valve| valve.name != "AA") {
+            return Err(ParseError::end_of_input(input, "a valve named AA"));
+        }
+
+        Ok(Self::new(&valves))
+    }
+
+    /// Keeps the valves with flow and `AA`, finding the walks between them
+    /// with Floyd-Warshall over the whole tunnel network.
+    pub fn new(valves: &[Valve]) -> Self {
+        // far enough to never be taken, yet safe to add to
+        const UNREACHABLE: u32 = u32::MAX / 4;
+
+        let index = valves
+            .iter()
+            .enumerate()
+            .map(|(i, valve)| (valve.name, i))
+            .collect::<HashMap<_, _>>();
+        let mut walks = vec![vec![UNREACHABLE; valves.len()]; valves.len()];
+        for (i, valve) in valves.iter().enumerate() {
+            walks[i][i] = 0;
+            for tunnel in &valve.tunnels {
+                walks[i][index[tunnel]] = 1;
+            }
+        }
+        for via in 0..valves.len() {
+            for from in 0..valves.len() {
+                for to in 0..valves.len() {
+                    let walk = walks[from][via] + walks[via][to];
+                    if walk < walks[from][to] {
+                        walks[from][to] = walk;
+                    }
+                }
+            }
+        }
+
+        let mut kept = (0..valves.len())
+            .filter(|i| valves[*i].flow > 0)
+            .collect::<Vec<_>>();
+        let start = match kept.iter().position(|i| valves[*i].name == "AA") {
+            Some(start) => start,
+            None => {
+                kept.push(index["AA"]);
+                kept.len() - 1
+            }
+        };
+
+        Self {
+            flows: kept
+                .iter()
+                .map(|i| valves[*i].flow)
+                .filter(|flow| *flow > 0)
+                .collect(),
+            distances: kept
+                .iter()
+                .map(|from| kept.iter().map(|to| walks[*from][*to]).collect())
+                .collect(),
+            start,
+        }
+    }
+}
+
+/// The most pressure that can be released within `minutes` by opening
+/// exactly each set of valves, indexed by the set as a bitmask over
+/// `network.flows`. Sets that cannot all be opened in time stay at 0.
+pub fn best_pressures(network: &Network, minutes: u32) -> Vec<u32> {
+    fn visit(network: &Network, best: &mut [u32], at: usize, left: u32, open: usize, total: u32) {
+        best[open] = best[open].max(total);
+
+        for (next, flow) in network.flows.iter().enumerate() {
+            if open & 1 << next != 0 {
+                continue;
+            }
+            // walking there and then spending a minute opening it
+            if let Some(left) = left.checked_sub(network.distances[at][next] + 1) {
+                visit(
+                    network,
+                    best,
+                    next,
+                    left,
+                    open | 1 << next,
+                    total + left * flow,
+                );
+            }
+        }
+    }
+
+    let mut best = vec![0; 1 << network.flows.len()];
+    visit(network, &mut best, network.start, minutes, 0, 0);
+    best
+}
+
+/// The most pressure one person can release within `minutes`. Unlike
+/// [`best_pressures`], this only needs the best plan overall, so it gives
+/// up on a plan once even walking straight to every closed valve from
+/// where it stands could not beat the best found so far.
+pub fn max_pressure(network: &Network, minutes: u32) -> u32 {
+    fn visit(network: &Network, best: &mut u32, at: usize, left: u32, open: usize, total: u32) {
+        *best = (*best).max(total);
+
+        let closed = (0..network.flows.len()).filter(|next| open & 1 << next == 0);
+        let bound = total
+            + closed
+                .clone()
+                .map(|next| {
+                    let opened_at = left.saturating_sub(network.distances[at][next] + 1);
+                    opened_at * network.flows[next]
+                })
+                .sum::<u32>();
+        if bound <= *best {
+            return;
+        }
+
+        for next in closed {
+            if let Some(left) = left.checked_sub(network.distances[at][next] + 1) {
+                let total = total + left * network.flows[next];
+                visit(network, best, next, left, open | 1 << next, total);
+            }
+        }
+    }
+
+    let mut best = 0;
+    visit(network, &mut best, network.start, minutes, 0, 0);
+    best
+}
+
+/// The most pressure two workers can release within `minutes`, each
+/// opening a different set of valves.
+pub fn max_pressure_in_pair(network: &Network, minutes: u32) -> u32 {
+    let mut best = best_pressures(network, minutes);
+
+    // widen each entry to the best over every subset of its valves, so
+    // that the split below can hand out all valves, opened or not
+    for valve in 0..network.flows.len() {
+        for open in 0..best.len() {
+            if open & 1 << valve != 0 {
+                best[open] = best[open].max(best[open ^ 1 << valve]);
+            }
+        }
+    }
+
+    let all = best.len() - 1;
+    (0..=all)
+        .map(|mine| best[mine] + best[all ^ mine])
+        .max()
+        .unwrap()
+}
+
+fn p1(network: &Network) -> Answer {
+    max_pressure(network, 30).into()
+}
+
+fn p2(network: &Network) -> Answer {
+    max_pressure_in_pair(network, 26).into()
+}
+
+/// `size.width` valves joined up into one network, with about every
+/// third one having a flow rate. At most 12 do, to keep the search quick.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let count = size.width.clamp(2, 26 * 26);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < count {
+        let name = (0..2)
+            .map(|_| (b'A' + rng.below(26) as u8) as char)
+            .collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    // a tree that reaches every valve, with a few shortcuts on top
+    let mut tunnels = vec![vec![]; count];
+    let mut dig = |from: usize, to: usize| {
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    };
+    for valve in 1..count {
+        dig(valve, rng.index(valve));
+    }
+    for _ in 0..count / 4 {
+        dig(rng.index(count), rng.index(count));
+    }
+
+    let mut useful = 0;
+    let mut lines = (0..count)
+        .map(|valve| {
+            let flow = if valve > 0 && useful < 12 && rng.chance(0.35) {
+                useful += 1;
+                rng.range(1..=25)
+            } else {
+                0
+            };
+            let listed = tunnels[valve].iter().map(|to| &names[*to]).join(", ");
+            let tunnels = if tunnels[valve].len() == 1 {
+                format!("tunnel leads to valve {}", listed)
+            } else {
+                format!("tunnels lead to valves {}", listed)
+            };
+            format!("Valve {} has flow rate={}; {}", names[valve], flow, tunnels)
+        })
+        .collect::<Vec<_>>();
+
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+    const SAMPLE: &'static str = include_str!("../bin/day16/sample.txt");
+
+    type Input<'a> = Network;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Network::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day16/sample.txt");
+
+    /// Tries every move and valve opening minute by minute, for `workers`
+    /// people starting at `AA` on the uncompressed network.
+    fn brute_force(input: &str, workers: usize, minutes: u32) -> u32 {
+        fn solve(
+            valves: &[(u32, Vec<usize>)],
+            memo: &mut HashMap<(u32, Vec<usize>, u64), u32>,
+            left: u32,
+            at: Vec<usize>,
+            open: u64,
+        ) -> u32 {
+            if left == 0 {
+                return 0;
+            }
+            if let Some(best) = memo.get(&(left, at.clone(), open)) {
+                return *best;
+            }
+
+            // every worker either opens the valve they are at or moves on
+            let choices = at.iter().map(|&valve| {
+                let mut choices = valves[valve]
+                    .1
+                    .iter()
+                    .map(|&next| (next, None))
+                    .collect::<Vec<_>>();
+                if valves[valve].0 > 0 && open & 1 << valve == 0 {
+                    choices.push((valve, Some(valve)));
+                }
+                choices
+            });
+            let best = choices
+                .multi_cartesian_product()
+                .filter_map(|moves| {
+                    let opened = moves.iter().filter_map(|(_, open)| *open).collect_vec();
+                    if !opened.iter().all_unique() {
+                        return None;
+                    }
+                    let released = opened
+                        .iter()
+                        .map(|valve| (left - 1) * valves[*valve].0)
+                        .sum::<u32>();
+                    let open = opened.iter().fold(open, |open, valve| open | 1 << valve);
+                    let at = moves.iter().map(|(next, _)| *next).collect();
+                    Some(released + solve(valves, memo, left - 1, at, open))
+                })
+                .max()
+                .unwrap_or(0);
+
+            memo.insert((left, at, open), best);
+            best
+        }
+
+        let valves = parse::lines(input)
+            .map(|line| Valve::parse(line).unwrap())
+            .collect::<Vec<_>>();
+        let index = |name: &str| valves.iter().position(|valve| valve.name == name).unwrap();
+        let graph = valves
+            .iter()
+            .map(|valve| {
+                let tunnels = valve.tunnels.iter().map(|name| index(name)).collect();
+                (valve.flow, tunnels)
+            })
+            .collect::<Vec<_>>();
+
+        let start = vec![index("AA"); workers];
+        solve(&graph, &mut HashMap::new(), minutes, start, 0)
+    }
+
+    #[test]
+    fn test_compressed_network() {
+        let network = Day16::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(network.flows, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(network.start, 6);
+        // AA to HH goes through DD, EE, FF and GG
+        assert_eq!(network.distances[6][4], 5);
+    }
+
+    #[test]
+    fn test_generated_networks() {
+        rng::check(20, |rng| {
+            let size = Size::new(rng.index(5) + 2, 1);
+            let input = generate(rng, size);
+            let network = Day16::parse(&input).unwrap();
+            let best = brute_force(&input, 1, 12);
+            assert_eq!(max_pressure(&network, 12), best);
+            assert_eq!(best_pressures(&network, 12).into_iter().max(), Some(best));
+            assert_eq!(max_pressure_in_pair(&network, 8), brute_force(&input, 2, 8));
+        });
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day16::parse(&SAMPLE_INPUT.replace("valves EE, GG", "valves EE, GX"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (6, 54));
+        assert_eq!(err.expected, "a valve that is listed somewhere");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day16::parse(SAMPLE_INPUT).unwrap()), "1651");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day16>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day16::parse(SAMPLE_INPUT).unwrap()), "1707");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day16>(Part::Two);
+    }
+}
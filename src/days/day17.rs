@@ -0,0 +1,310 @@
+//! Day 17: Pyroclastic Flow.
+
+use std::collections::HashMap;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    visualize::{Frame, Recorder},
+    Answer, Solution,
+};
+
+/// The chamber is 7 units wide, each row a bitmask with the leftmost
+/// column in the highest bit.
+pub const WIDTH: usize = 7;
+
+/// A rock, as its rows from the bottom up, already placed two units from
+/// the left wall as it appears. Shorter rocks are padded with empty rows.
+pub type Rock = [u8; 4];
+
+/// The rocks in the order they fall, repeating after the last.
+pub const ROCKS: [Rock; 5] = [
+    [0b0011110, 0, 0, 0],
+    [0b0001000, 0b0011100, 0b0001000, 0],
+    [0b0011100, 0b0000100, 0b0000100, 0],
+    [0b0010000; 4],
+    [0b0011000, 0b0011000, 0, 0],
+];
+
+/// How far down from the top [`Chamber::surface`] looks into each column.
+/// Anything deeper is taken to no longer matter to the rocks still to
+/// fall, which also keeps a column that no rock lands in from making every
+/// surface look new.
+const SURFACE_DEPTH: usize = 64;
+
+/// A push from the jets of hot gas, one unit sideways.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    /// Pushes `rock` against the walls, which fails if it touches one.
+    pub fn push(self, rock: Rock) -> Option<Rock> {
+        let (wall, shift): (u8, fn(u8) -> u8) = match self {
+            Jet::Left => (1 << (WIDTH - 1), |row| row << 1),
+            Jet::Right => (1, |row| row >> 1),
+        };
+        rock.iter()
+            .all(|row| row & wall == 0)
+            .then(|| rock.map(shift))
+    }
+}
+
+/// The settled rocks, and how far along the rocks and the jet pattern are.
+#[derive(Debug, Clone, Default)]
+pub struct Chamber {
+    /// Every row up to the highest rock, from the floor up.
+    pub rows: Vec<u8>,
+    /// The number of rocks that have come to rest.
+    pub rocks: usize,
+    /// Where the next push comes from in the jet pattern.
+    pub jet: usize,
+}
+
+impl Chamber {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether `rock`, with its bottom row at `bottom`, overlaps a settled
+    /// rock.
+    pub fn collides(&self, rock: &Rock, bottom: usize) -> bool {
+        rock.iter()
+            .zip(self.rows.iter().skip(bottom))
+            .any(|(rock, row)| rock & row != 0)
+    }
+
+    /// Lets the next rock fall, pushed by `jets` in turn, until it comes
+    /// to rest.
+    pub fn drop_rock(&mut self, jets: &[Jet]) {
+        let mut rock = ROCKS[self.rocks % ROCKS.len()];
+        let mut bottom = self.height() + 3;
+
+        loop {
+            let jet = jets[self.jet];
+            self.jet = (self.jet + 1) % jets.len();
+            if let Some(pushed) = jet
+                .push(rock)
+                .filter(|pushed| !self.collides(pushed, bottom))
+            {
+                rock = pushed;
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (i, row) in rock.into_iter().enumerate().filter(|(_, row)| *row != 0) {
+            if bottom + i == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + i] |= row;
+        }
+        self.rocks += 1;
+    }
+
+    /// How far down from the top the first rock in each column is, up to
+    /// [`SURFACE_DEPTH`]. The floor counts as a rock.
+    pub fn surface(&self) -> [usize; WIDTH] {
+        let mut depths = [SURFACE_DEPTH; WIDTH];
+        for (column, depth) in depths.iter_mut().enumerate() {
+            let bit = 1 << (WIDTH - 1 - column);
+            if let Some(found) = self
+                .rows
+                .iter()
+                .rev()
+                .chain([&u8::MAX])
+                .take(SURFACE_DEPTH)
+                .position(|row| row & bit != 0)
+            {
+                *depth = found;
+            }
+        }
+        depths
+    }
+
+    /// The top `view` rows of the chamber, with the walls, and the floor
+    /// once it is in view.
+    pub fn draw(&self, view: usize) -> Frame {
+        let mut rows = self
+            .rows
+            .iter()
+            .rev()
+            .take(view)
+            .map(|row| {
+                let cells = (0..WIDTH)
+                    .rev()
+                    .map(|bit| if row & 1 << bit != 0 { '#' } else { '.' });
+                ['|'].into_iter().chain(cells).chain(['|']).collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        if view > self.rows.len() {
+            rows.push(['+'].into_iter().chain(['-'; WIDTH]).chain(['+']).collect());
+        }
+        Frame::from_rows(rows)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let mut lines = parse::lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a jet pattern"))?;
+    let jets = line
+        .chars("a jet (< or >)", |ch| ch == '<' || ch == '>')?
+        .chars()
+        .map(|ch| if ch == '<' { Jet::Left } else { Jet::Right })
+        .collect();
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "end of input"));
+    }
+    Ok(jets)
+}
+
+/// The height of the tower after `rocks` rocks have fallen. As soon as a
+/// rock settles with the same rock, jet and [`Chamber::surface`] as an
+/// earlier one, everything in between repeats, so the repeats are added up
+/// instead of simulated.
+pub fn height_after(jets: &[Jet], rocks: u64) -> u64 {
+    let mut chamber = Chamber::default();
+    let mut seen = Some(HashMap::new());
+    let mut skipped = 0;
+    let mut remaining = rocks;
+
+    while remaining > 0 {
+        chamber.drop_rock(jets);
+        remaining -= 1;
+
+        let Some(states) = seen.as_mut() else {
+            continue;
+        };
+        let state = (chamber.rocks % ROCKS.len(), chamber.jet, chamber.surface());
+        if let Some((rocks, height)) = states.insert(state, (chamber.rocks, chamber.height())) {
+            let period = (chamber.rocks - rocks) as u64;
+            let cycles = remaining / period;
+            remaining -= cycles * period;
+            skipped = cycles * (chamber.height() - height) as u64;
+            seen = None;
+        }
+    }
+
+    chamber.height() as u64 + skipped
+}
+
+fn p1(jets: &[Jet]) -> Answer {
+    height_after(jets, 2022).into()
+}
+
+fn p2(jets: &[Jet]) -> Answer {
+    height_after(jets, 1_000_000_000_000).into()
+}
+
+/// Drops the rocks of part 1, drawing the top of the chamber each time one
+/// comes to rest.
+fn visualize(jets: &[Jet], recorder: &mut Recorder) {
+    const VIEW: usize = 40;
+
+    let mut chamber = Chamber::default();
+    let mut more = recorder.record(&chamber.draw(VIEW));
+    while more && chamber.rocks < 2022 {
+        chamber.drop_rock(jets);
+        more = recorder.record(&chamber.draw(VIEW));
+    }
+}
+
+/// A jet pattern of `size.width` pushes.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.width.max(1))
+        .map(|_| if rng.chance(0.5) { '<' } else { '>' })
+        .collect()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+    const SAMPLE: &'static str = include_str!("../bin/day17/sample.txt");
+
+    type Input<'a> = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn visualize(input: &Self::Input<'_>, recorder: &mut Recorder) -> bool {
+        visualize(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day17/sample.txt");
+
+    #[test]
+    fn test_drop_rock() {
+        let jets = Day17::parse(SAMPLE_INPUT).unwrap();
+        let mut chamber = Chamber::default();
+        chamber.drop_rock(&jets);
+        chamber.drop_rock(&jets);
+        assert_eq!(
+            chamber.draw(10).to_string(),
+            "|...#...|\n|..###..|\n|...#...|\n|..####.|\n+-------+"
+        );
+        assert_eq!(chamber.surface(), [4, 4, 1, 0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_cycles_match_simulation() {
+        rng::check(20, |rng| {
+            let size = Size::new(rng.index(60) + 1, 1);
+            let jets = Day17::parse(&generate(rng, size)).unwrap();
+            let rocks = rng.range(1..=3000) as usize;
+
+            let mut chamber = Chamber::default();
+            (0..rocks).for_each(|_| chamber.drop_rock(&jets));
+            assert_eq!(height_after(&jets, rocks as u64), chamber.height() as u64);
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day17::parse(SAMPLE_INPUT).unwrap()), "3068");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day17>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day17::parse(SAMPLE_INPUT).unwrap()), "1514285714288");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day17>(Part::Two);
+    }
+}
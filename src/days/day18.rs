@@ -0,0 +1,273 @@
+//! Day 18: Boiling Boulders.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    point::Point3,
+    rng::Rng,
+    search, Answer, Solution,
+};
+
+/// A unit square, as the ranges `(x0, x1, y0, y1, z0, z1)` it spans, one
+/// of which is empty.
+pub type Surface = (i32, i32, i32, i32, i32, i32);
+/// A unit cube, by its corner nearest the origin.
+pub type Cube = Point3;
+
+pub fn sides(cube: &Cube) -> Vec<Surface> {
+    vec![
+        (cube.x, cube.x, cube.y, cube.y + 1, cube.z, cube.z + 1),
+        (
+            cube.x + 1,
+            cube.x + 1,
+            cube.y,
+            cube.y + 1,
+            cube.z,
+            cube.z + 1,
+        ),
+        (cube.x, cube.x + 1, cube.y, cube.y, cube.z, cube.z + 1),
+        (
+            cube.x,
+            cube.x + 1,
+            cube.y + 1,
+            cube.y + 1,
+            cube.z,
+            cube.z + 1,
+        ),
+        (cube.x, cube.x + 1, cube.y, cube.y + 1, cube.z, cube.z),
+        (
+            cube.x,
+            cube.x + 1,
+            cube.y,
+            cube.y + 1,
+            cube.z + 1,
+            cube.z + 1,
+        ),
+    ]
+}
+
+pub fn get_droplets(input: &str) -> Result<HashSet<Cube>, ParseError> {
+    let droplets = parse::each_line(input, "a cube \"x,y,z\"", |line| {
+        let values = line.text.split(',').collect::<Vec<_>>();
+        let [x, y, z] = values[..] else {
+            let extra = values.get(3).copied();
+            return Err(line.error(
+                extra.unwrap_or(&line.text[line.text.len()..]),
+                "three coordinates \"x,y,z\"",
+            ));
+        };
+        let coordinate = |value: &str| {
+            // the flood fill in get_p2_surfaces only explores -1..=22
+            line.parse::<i32>(value, "a coordinate")
+                .ok()
+                .filter(|value| (0..=21).contains(value))
+                .ok_or_else(|| line.error(value, "a coordinate from 0 to 21"))
+        };
+        Ok(Point3::new(coordinate(x)?, coordinate(y)?, coordinate(z)?))
+    })?;
+    Ok(droplets.into_iter().collect())
+}
+
+/// Every side of a cube that is not shared with another cube.
+pub fn get_p1_surfaces(droplets: &HashSet<Cube>) -> HashSet<Surface> {
+    let mut seen = HashMap::new();
+
+    droplets.iter().for_each(|cube| {
+        sides(cube).into_iter().for_each(|surface| {
+            *seen.entry(surface).or_insert(0) += 1;
+        });
+    });
+
+    seen.into_iter()
+        .filter(|(_, count)| *count == 1)
+        .map(|(surface, _)| surface)
+        .collect()
+}
+
+/// The sides among `p1_surfaces` that face the air outside the droplet,
+/// rather than a pocket trapped inside it.
+pub fn get_p2_surfaces(
+    droplets: &HashSet<Cube>,
+    p1_surfaces: &HashSet<Surface>,
+) -> HashSet<Surface> {
+    // flood the air around the droplet, within a box one unit larger than
+    // the coordinates allowed by get_droplets, from a corner of that box
+    // that no cube can be in
+    let outside = search::reachable([Point3::new(-1, -1, -1)], |current| {
+        current
+            .neighbours6()
+            .filter(|cube| {
+                [cube.x, cube.y, cube.z]
+                    .into_iter()
+                    .all(|value| (-1..=22).contains(&value))
+                    && !droplets.contains(cube)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // one of the two cubes on either side of a surface is in the droplet,
+    // and the other is the air that the surface faces
+    p1_surfaces
+        .iter()
+        .filter(|surface| {
+            let far = Point3::new(surface.0, surface.2, surface.4);
+            let near = match surface {
+                (x0, x1, ..) if x0 == x1 => far - Point3::new(1, 0, 0),
+                (_, _, y0, y1, ..) if y0 == y1 => far - Point3::new(0, 1, 0),
+                _ => far - Point3::new(0, 0, 1),
+            };
+            outside.contains(&far) || outside.contains(&near)
+        })
+        .copied()
+        .collect()
+}
+
+fn p1(droplets: &HashSet<Cube>) -> Answer {
+    get_p1_surfaces(droplets).len().into()
+}
+
+fn p2(droplets: &HashSet<Cube>) -> Answer {
+    get_p2_surfaces(droplets, &get_p1_surfaces(droplets))
+        .len()
+        .into()
+}
+
+/// `size.width` distinct cubes, or as many as fit, inside a box
+/// `size.height` wide. The box is at most the 22 wide that the parser
+/// allows.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let side = size.height.min(22) as i64;
+    let count = size.width.min(side.pow(3) as usize);
+
+    let mut droplets = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < count {
+        let mut coordinate = || rng.range(0..=side - 1) as i32;
+        let cube = Cube::new(coordinate(), coordinate(), coordinate());
+        if droplets.insert(cube) {
+            lines.push(format!("{},{},{}", cube.x, cube.y, cube.z));
+        }
+    }
+    lines.join("\n")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+    const SAMPLE: &'static str = include_str!("../bin/day18/sample.txt");
+
+    type Input<'a> = HashSet<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        get_droplets(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+    use itertools::Itertools;
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day18/sample.txt");
+
+    #[test]
+    fn test_against_counting_faces() {
+        rng::check(20, |rng| {
+            let size = Size::new(rng.index(200) + 1, rng.index(8) + 1);
+            let droplets = Day18::parse(&generate(rng, size)).unwrap();
+
+            // every cube has six sides, less those it shares with another
+            let shared = droplets
+                .iter()
+                .map(|cube| {
+                    cube.neighbours6()
+                        .filter(|other| droplets.contains(other))
+                        .count()
+                })
+                .sum::<usize>();
+            assert_eq!(
+                get_p1_surfaces(&droplets).len(),
+                6 * droplets.len() - shared
+            );
+
+            // the air around the droplet, in a box one unit larger than the
+            // generated one, touches it once for every side that faces out
+            let bound = size.height as i32;
+            let in_box = |cube: &Cube| {
+                [cube.x, cube.y, cube.z]
+                    .iter()
+                    .all(|value| (-1..=bound).contains(value))
+            };
+            let start = Cube::new(-1, -1, -1);
+            let (mut outside, mut pending, mut sides) = (HashSet::from([start]), vec![start], 0);
+            while let Some(air) = pending.pop() {
+                for next in air.neighbours6() {
+                    if droplets.contains(&next) {
+                        sides += 1;
+                    } else if in_box(&next) && outside.insert(next) {
+                        pending.push(next);
+                    }
+                }
+            }
+            assert_eq!(p2(&droplets), sides.to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_p2_pocket() {
+        // a 4x3x3 block with a pocket two cubes long along x in the middle
+        let input = (0..4)
+            .cartesian_product(0..3)
+            .cartesian_product(0..3)
+            .filter(|((x, y), z)| !(*y == 1 && *z == 1 && (1..=2).contains(x)))
+            .map(|((x, y), z)| format!("{},{},{}", x, y, z))
+            .join("\n");
+        let droplets = Day18::parse(&input).unwrap();
+        assert_eq!(p1(&droplets), "76");
+        assert_eq!(p2(&droplets), "66");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day18::parse("").err().unwrap();
+        assert_eq!(err.expected, "a cube \"x,y,z\"");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day18::parse(SAMPLE_INPUT).unwrap()), "64");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day18>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day18::parse(SAMPLE_INPUT).unwrap()), "58");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day18>(Part::Two);
+    }
+}
@@ -0,0 +1,357 @@
+//! Day 19: Not Enough Minerals.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, Line, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// The minerals, in the order that robots and their costs are indexed by.
+pub const MINERALS: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+pub const GEODE: usize = 3;
+
+/// How much a robot costs, or how much of each mineral there is, indexed
+/// as [`MINERALS`].
+pub type Minerals = [u32; 4];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    /// The cost of each robot, indexed by the mineral it collects.
+    pub costs: [Minerals; 4],
+}
+
+impl Blueprint {
+    pub fn parse(line: Line) -> Result<Self, ParseError> {
+        let mineral = |name: &str| {
+            MINERALS
+                .iter()
+                .position(|mineral| *mineral == name)
+                .ok_or_else(|| line.error(name, "a mineral (ore, clay, obsidian or geode)"))
+        };
+
+        let rest = line.strip_prefix("Blueprint ")?;
+        let (id, rest) = rest
+            .split_once(": ")
+            .ok_or_else(|| line.error(&rest[rest.len()..], "\": \""))?;
+
+        let mut costs = [None; 4];
+        for sentence in rest.split_terminator('.').map(str::trim_start) {
+            let sentence = sentence
+                .strip_prefix("Each ")
+                .ok_or_else(|| line.error(sentence, "\"Each <mineral> robot costs\""))?;
+            let (robot, price) = sentence
+                .split_once(" robot costs ")
+                .ok_or_else(|| line.error(&sentence[sentence.len()..], "\" robot costs \""))?;
+
+            let mut cost = [0; 4];
+            for item in price.split(" and ") {
+                let (amount, kind) = item
+                    .split_once(' ')
+                    .ok_or_else(|| line.error(item, "\"<amount> <mineral>\""))?;
+                cost[mineral(kind)?] = line.parse(amount, "an amount")?;
+            }
+            if costs[mineral(robot)?].replace(cost).is_some() {
+                return Err(line.error(robot, "a robot that was not priced before"));
+            }
+        }
+
+        let mut priced = [[0; 4]; 4];
+        for (robot, cost) in costs.into_iter().enumerate() {
+            priced[robot] = cost.ok_or_else(|| {
+                line.error(
+                    &line.text[line.text.len()..],
+                    format!("the cost of the {} robot", MINERALS[robot]),
+                )
+            })?;
+        }
+
+        Ok(Self {
+            id: line.parse(id, "a blueprint number")?,
+            costs: priced,
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse::each_line(input, "a blueprint", Blueprint::parse)
+}
+
+/// The robots collecting for the factory, and what they have collected.
+#[derive(Debug, Clone, Copy)]
+struct State {
+    robots: Minerals,
+    stock: Minerals,
+    left: u32,
+}
+
+/// The most geodes that can be opened within `minutes`, starting with a
+/// single ore robot.
+///
+/// Rather than deciding minute by minute, each step picks the next robot
+/// to build and skips ahead to when it is built. Robots of a mineral are
+/// never built beyond what the factory can spend of it in one minute, and
+/// a plan is dropped once even an optimistic estimate cannot beat the best
+/// found so far. Blueprints share nothing, so any number of them can be
+/// searched at once.
+pub fn max_geodes(blueprint: &Blueprint, minutes: u32) -> u32 {
+    let mut max_spend = [u32::MAX; 4];
+    for mineral in 0..GEODE {
+        max_spend[mineral] = blueprint
+            .costs
+            .iter()
+            .map(|cost| cost[mineral])
+            .max()
+            .unwrap();
+    }
+
+    fn search(blueprint: &Blueprint, max_spend: &Minerals, state: State, best: &mut u32) {
+        let idle = state.stock[GEODE] + state.robots[GEODE] * state.left;
+        *best = (*best).max(idle);
+        if upper_bound(blueprint, state) <= *best {
+            return;
+        }
+
+        for robot in (0..MINERALS.len()).rev() {
+            if state.robots[robot] >= max_spend[robot] {
+                continue;
+            }
+
+            // the minutes spent collecting until the robot is affordable
+            let cost = blueprint.costs[robot];
+            let Some(wait) = (0..MINERALS.len())
+                .map(|mineral| {
+                    let missing = cost[mineral].saturating_sub(state.stock[mineral]);
+                    match (missing, state.robots[mineral]) {
+                        (0, _) => Some(0),
+                        (_, 0) => None,
+                        (missing, robots) => Some(missing.div_ceil(robots)),
+                    }
+                })
+                .fold_options(0, u32::max)
+            else {
+                continue;
+            };
+            // and one more to build it, which only pays off with time left
+            if wait + 1 >= state.left {
+                continue;
+            }
+
+            let mut next = State {
+                robots: state.robots,
+                stock: std::array::from_fn(|mineral| {
+                    state.stock[mineral] + state.robots[mineral] * (wait + 1) - cost[mineral]
+                }),
+                left: state.left - wait - 1,
+            };
+            next.robots[robot] += 1;
+            search(blueprint, max_spend, next, best);
+        }
+    }
+
+    let mut best = 0;
+    let start = State {
+        robots: [1, 0, 0, 0],
+        stock: [0; 4],
+        left: minutes,
+    };
+    search(blueprint, &max_spend, start, &mut best);
+    best
+}
+
+/// The geodes opened if ore and clay were free, a new obsidian robot came
+/// every minute, and a geode robot was built alongside whenever the
+/// obsidian was there. This can only beat what `state` can really reach.
+fn upper_bound(blueprint: &Blueprint, state: State) -> u32 {
+    const OBSIDIAN: usize = 2;
+    let cost = blueprint.costs[GEODE][OBSIDIAN];
+    let (mut obsidian, mut obsidian_robots) = (state.stock[OBSIDIAN], state.robots[OBSIDIAN]);
+    let (mut geodes, mut geode_robots) = (state.stock[GEODE], state.robots[GEODE]);
+
+    for _ in 0..state.left {
+        let build = obsidian >= cost;
+        if build {
+            obsidian -= cost;
+        }
+        obsidian += obsidian_robots;
+        geodes += geode_robots;
+        obsidian_robots += 1;
+        if build {
+            geode_robots += 1;
+        }
+    }
+
+    geodes
+}
+
+fn p1(blueprints: &[Blueprint]) -> Answer {
+    blueprints
+        .iter()
+        .map(|blueprint| blueprint.id * max_geodes(blueprint, 24))
+        .sum::<u32>()
+        .into()
+}
+
+fn p2(blueprints: &[Blueprint]) -> Answer {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| max_geodes(blueprint, 32))
+        .product::<u32>()
+        .into()
+}
+
+/// `size.width` blueprints, with the clay and obsidian costs going up to
+/// `size.height` like the ore costs go up to 4.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let high = size.height.max(2) as i64;
+    (1..=size.width)
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. \
+                 Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.",
+                id,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=high),
+                rng.range(2..=4),
+                rng.range(2..=high),
+            )
+        })
+        .join("\n")
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 19;
+    const SAMPLE: &'static str = include_str!("../bin/day19/sample.txt");
+
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+    use std::collections::HashMap;
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day19/sample.txt");
+
+    /// Tries building every affordable robot, or nothing, every minute.
+    fn brute_force(blueprint: &Blueprint, minutes: u32) -> u32 {
+        fn search(
+            blueprint: &Blueprint,
+            memo: &mut HashMap<(Minerals, Minerals, u32), u32>,
+            robots: Minerals,
+            stock: Minerals,
+            left: u32,
+        ) -> u32 {
+            if left == 0 {
+                return stock[GEODE];
+            }
+            if let Some(best) = memo.get(&(robots, stock, left)) {
+                return *best;
+            }
+
+            let collected = |stock: Minerals| {
+                let mut stock = stock;
+                (0..4).for_each(|mineral| stock[mineral] += robots[mineral]);
+                stock
+            };
+            let mut best = search(blueprint, memo, robots, collected(stock), left - 1);
+            for (robot, cost) in blueprint.costs.iter().enumerate() {
+                if (0..4).all(|mineral| stock[mineral] >= cost[mineral]) {
+                    let mut paid = stock;
+                    (0..4).for_each(|mineral| paid[mineral] -= cost[mineral]);
+                    let mut built = robots;
+                    built[robot] += 1;
+                    best = best.max(search(blueprint, memo, built, collected(paid), left - 1));
+                }
+            }
+
+            memo.insert((robots, stock, left), best);
+            best
+        }
+
+        search(
+            blueprint,
+            &mut HashMap::new(),
+            [1, 0, 0, 0],
+            [0; 4],
+            minutes,
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let blueprints = Day19::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(blueprints[1].id, 2);
+        assert_eq!(
+            blueprints[1].costs,
+            [[2, 0, 0, 0], [3, 0, 0, 0], [3, 8, 0, 0], [3, 0, 12, 0]]
+        );
+
+        let err = Day19::parse(&SAMPLE_INPUT.replace("costs 2 ore. Each clay", "costs 2 ore."))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 42));
+        assert_eq!(err.expected, "\"Each <mineral> robot costs\"");
+
+        let err = Day19::parse("").err().unwrap();
+        assert_eq!(err.expected, "a blueprint");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_max_geodes_against_brute_force() {
+        rng::check(20, |rng| {
+            let blueprints = Day19::parse(&generate(rng, Size::new(1, 4))).unwrap();
+            let blueprint = &blueprints[0];
+            assert_eq!(max_geodes(blueprint, 14), brute_force(blueprint, 14));
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day19::parse(SAMPLE_INPUT).unwrap()), "33");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day19>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day19::parse(SAMPLE_INPUT).unwrap()), "3472");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day19>(Part::Two);
+    }
+}
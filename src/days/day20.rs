@@ -0,0 +1,235 @@
+//! Day 20: Grove Positioning System.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+struct Node {
+    value: i64,
+    next: usize,
+    prev: usize,
+}
+
+/// The encrypted file, which must contain a 0 to count from.
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = parse::lines(input)
+        .map(|line| line.parse::<i64>(line.text, "a number"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !numbers.contains(&0) {
+        return Err(ParseError::end_of_input(input, "a 0 somewhere in the file"));
+    }
+    Ok(numbers)
+}
+
+fn build_list(numbers: &[i64], decryption_key: i64) -> Vec<Node> {
+    let mut result = numbers
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| Node {
+            value: value * decryption_key,
+            next: idx + 1,
+            prev: if idx == 0 { 0 } else { idx - 1 },
+        })
+        .collect::<Vec<_>>();
+
+    result.get_mut(0).unwrap().prev = result.len() - 1;
+    result.iter_mut().last().unwrap().next = 0;
+
+    result
+}
+
+fn find_zero_value_pos(list: &[Node]) -> usize {
+    list.iter()
+        .enumerate()
+        .find(|(_, node)| node.value == 0)
+        .unwrap()
+        .0
+}
+
+fn find_nth_value_from_zero(list: &[Node], nth: usize) -> i64 {
+    let nth = nth % list.len();
+    let mut cur = find_zero_value_pos(list);
+    for _ in 0..nth {
+        cur = list[cur].next;
+    }
+    list[cur].value
+}
+
+/// Multiplies every number by `decryption_key`, mixes the file `mix_count`
+/// times, and adds up the grove coordinates: the 1000th, 2000th and 3000th
+/// numbers after the 0.
+pub fn decrypt(numbers: &[i64], decryption_key: i64, mix_count: usize) -> i64 {
+    let mut list = build_list(numbers, decryption_key);
+
+    for _ in 0..mix_count {
+        for i in 0..(list.len()) {
+            if list[i].value == 0 {
+                continue;
+            }
+
+            // remove ith node
+            let prev = list[i].prev;
+            let next = list[i].next;
+            list[prev].next = next;
+            list[next].prev = prev;
+
+            // find the new spot position
+            let mut cur = prev;
+            if list[i].value > 0 {
+                let steps = (list[i].value as usize) % (list.len() - 1);
+                for _ in 0..steps {
+                    cur = list[cur].next;
+                }
+            } else {
+                let steps = (-list[i].value as usize) % (list.len() - 1);
+                for _ in 0..steps {
+                    cur = list[cur].prev;
+                }
+            }
+
+            // insert ith node after finding its correct position
+            let next = list[cur].next;
+            list[cur].next = i;
+            list[i].prev = cur;
+            list[next].prev = i;
+            list[i].next = next;
+        }
+    }
+
+    find_nth_value_from_zero(&list, 1000)
+        + find_nth_value_from_zero(&list, 2000)
+        + find_nth_value_from_zero(&list, 3000)
+}
+
+fn p1(numbers: &[i64]) -> Answer {
+    decrypt(numbers, 1, 1).into()
+}
+
+fn p2(numbers: &[i64]) -> Answer {
+    decrypt(numbers, 811589153, 10).into()
+}
+
+/// `size.width` numbers within `size.height` either side of 0, with a
+/// single 0 among them.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let bound = size.height as i64;
+    let mut numbers = (1..size.width)
+        .map(|_| {
+            let number = rng.range(1..=bound);
+            if rng.chance(0.5) {
+                -number
+            } else {
+                number
+            }
+        })
+        .collect::<Vec<_>>();
+    numbers.insert(rng.index(numbers.len() + 1), 0);
+    numbers.iter().join("\n")
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+    const SAMPLE: &'static str = include_str!("../bin/day20/sample.txt");
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day20/sample.txt");
+
+    /// Mixes by moving the numbers around in a plain vector.
+    fn decrypt_naive(numbers: &[i64], decryption_key: i64, mix_count: usize) -> i64 {
+        let mut mixed = numbers
+            .iter()
+            .map(|number| number * decryption_key)
+            .enumerate()
+            .collect::<Vec<_>>();
+        let len = mixed.len() as i64;
+        for _ in 0..mix_count {
+            for original in 0..mixed.len() {
+                let from = mixed
+                    .iter()
+                    .position(|(index, _)| *index == original)
+                    .unwrap();
+                let entry = mixed.remove(from);
+                let to = (from as i64 + entry.1).rem_euclid((len - 1).max(1));
+                mixed.insert(to as usize, entry);
+            }
+        }
+
+        let zero = mixed.iter().position(|(_, value)| *value == 0).unwrap();
+        [1000, 2000, 3000]
+            .iter()
+            .map(|nth| mixed[(zero + nth) % mixed.len()].1)
+            .sum()
+    }
+
+    #[test]
+    fn test_against_naive_mixing() {
+        rng::check(100, |rng| {
+            let size = Size::new(rng.index(30) + 1, rng.index(50) + 1);
+            let numbers = Day20::parse(&generate(rng, size)).unwrap();
+            assert_eq!(
+                decrypt(&numbers, 1, 1),
+                decrypt_naive(&numbers, 1, 1),
+                "{:?}",
+                numbers
+            );
+            assert_eq!(
+                decrypt(&numbers, 811589153, 10),
+                decrypt_naive(&numbers, 811589153, 10),
+                "{:?}",
+                numbers
+            );
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day20::parse(SAMPLE_INPUT).unwrap()), "3");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day20>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day20::parse(SAMPLE_INPUT).unwrap()), "1623178306");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day20>(Part::Two);
+    }
+}
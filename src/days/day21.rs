@@ -0,0 +1,415 @@
+//! Day 21: Monkey Math.
+
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// What a monkey yells: a number, or the result of an operation on what
+/// two other monkeys yell.
+pub enum Job<'a> {
+    Add(&'a str, &'a str),
+    Sub(&'a str, &'a str),
+    Mul(&'a str, &'a str),
+    Div(&'a str, &'a str),
+    Number(i64),
+}
+
+/// Every monkey's job by its name, checked to have a `root` waiting on two
+/// monkeys, a `humn`, and no references to unlisted monkeys.
+pub fn parse_graph(input: &str) -> Result<HashMap<&str, Job<'_>>, ParseError> {
+    let mut graph = HashMap::new();
+    let mut references = vec![];
+
+    for line in parse::lines(input) {
+        let (name, content) = line.split_once(": ")?;
+        if graph.contains_key(name) {
+            return Err(line.error(name, "a monkey that was not listed before"));
+        }
+
+        let words = content.split_whitespace().collect::<Vec<_>>();
+        let job = match words[..] {
+            [number] => Job::Number(line.parse(number, "a number")?),
+            [left, op, right] => {
+                references.push((line, left));
+                references.push((line, right));
+                match op {
+                    "+" => Job::Add(left, right),
+                    "-" => Job::Sub(left, right),
+                    "*" => Job::Mul(left, right),
+                    "/" => Job::Div(left, right),
+                    _ => return Err(line.error(op, "an operator (+, -, * or /)")),
+                }
+            }
+            _ => {
+                return Err(line.error(
+                    words.get(1).copied().unwrap_or(content),
+                    "a number or \"<monkey> <op> <monkey>\"",
+                ))
+            }
+        };
+
+        graph.insert(name, job);
+    }
+
+    if let Some((line, name)) = references
+        .into_iter()
+        .find(|(_, name)| !graph.contains_key(name))
+    {
+        return Err(line.error(name, "a monkey that is listed somewhere"));
+    }
+    match graph.get("root") {
+        Some(Job::Number(..)) | None => {
+            return Err(ParseError::end_of_input(
+                input,
+                "a root monkey waiting on two others",
+            ))
+        }
+        _ => {}
+    }
+    if !graph.contains_key("humn") {
+        return Err(ParseError::end_of_input(input, "a humn monkey"));
+    }
+
+    Ok(graph)
+}
+
+/// The number that the monkey `name` yells.
+pub fn evaluate(graph: &HashMap<&str, Job>, name: &str) -> i64 {
+    fn traverse(graph: &HashMap<&str, Job>, name: &str) -> i64 {
+        let job = graph.get(name).unwrap();
+
+        match job {
+            Job::Number(value) => *value,
+            Job::Add(left, right)
+            | Job::Sub(left, right)
+            | Job::Mul(left, right)
+            | Job::Div(left, right) => {
+                let left = traverse(graph, left);
+                let right = traverse(graph, right);
+                match job {
+                    Job::Add(..) => left + right,
+                    Job::Sub(..) => left - right,
+                    Job::Mul(..) => left * right,
+                    Job::Div(..) => left / right,
+                    _ => unreachable!("Already filtered"),
+                }
+            }
+        }
+    }
+
+    traverse(graph, name)
+}
+
+/// The number `humn` has to yell for both monkeys under `root` to yell the
+/// same number. `humn` must appear only once in the graph.
+pub fn find_humn<'a>(graph: &HashMap<&'a str, Job<'a>>) -> i64 {
+    let mut computed = HashMap::new();
+
+    fn traverse<'a>(
+        graph: &HashMap<&'a str, Job<'a>>,
+        name: &'a str,
+        computed: &mut HashMap<&'a str, Option<i64>>,
+    ) -> Option<i64> {
+        let job = graph.get(name).unwrap();
+
+        let result = match job {
+            Job::Number(value) => {
+                if name == "humn" {
+                    None
+                } else {
+                    Some(*value)
+                }
+            }
+            Job::Add(left, right)
+            | Job::Sub(left, right)
+            | Job::Mul(left, right)
+            | Job::Div(left, right) => {
+                let left = traverse(graph, left, computed);
+                let right = traverse(graph, right, computed);
+
+                match (left, right) {
+                    (Some(left), Some(right)) => Some(match job {
+                        Job::Add(..) => left + right,
+                        Job::Sub(..) => left - right,
+                        Job::Mul(..) => left * right,
+                        Job::Div(..) => left / right,
+                        _ => unreachable!("Already filtered"),
+                    }),
+                    _ => None,
+                }
+            }
+        };
+
+        computed.insert(name, result);
+        result
+    }
+
+    traverse(graph, "root", &mut computed);
+
+    let (left, right) = match graph["root"] {
+        Job::Add(left, right)
+        | Job::Sub(left, right)
+        | Job::Mul(left, right)
+        | Job::Div(left, right) => (left, right),
+        _ => panic!("Root should have two monkeys"),
+    };
+
+    let left_root = computed[left];
+    let right_root = computed[right];
+
+    fn propagate_to_humn(
+        graph: &HashMap<&str, Job>,
+        name: &str,
+        acc: i64,
+        computed: &HashMap<&str, Option<i64>>,
+    ) -> i64 {
+        let job = graph.get(name).unwrap();
+
+        match job {
+            Job::Number(..) => {
+                if name == "humn" {
+                    acc
+                } else {
+                    panic!("Should not process non-humn for this method.");
+                }
+            }
+            Job::Add(left, right)
+            | Job::Sub(left, right)
+            | Job::Mul(left, right)
+            | Job::Div(left, right) => {
+                let left_root = computed[left];
+                let right_root = computed[right];
+
+                if let Some(left_value) = left_root {
+                    propagate_to_humn(
+                        graph,
+                        right,
+                        match job {
+                            Job::Add(..) => acc - left_value,
+                            Job::Sub(..) => left_value - acc,
+                            Job::Mul(..) => acc / left_value,
+                            Job::Div(..) => left_value / acc,
+                            _ => unreachable!("Already filtered"),
+                        },
+                        computed,
+                    )
+                } else if let Some(right_value) = right_root {
+                    propagate_to_humn(
+                        graph,
+                        left,
+                        match job {
+                            Job::Add(..) => acc - right_value,
+                            Job::Sub(..) => acc + right_value,
+                            Job::Mul(..) => acc / right_value,
+                            Job::Div(..) => acc * right_value,
+                            _ => unreachable!("Already filtered"),
+                        },
+                        computed,
+                    )
+                } else {
+                    panic!("Either inner side should give a concrete answer.");
+                }
+            }
+        }
+    }
+
+    if let Some(left_value) = left_root {
+        propagate_to_humn(graph, right, left_value, &computed)
+    } else if let Some(right_value) = right_root {
+        propagate_to_humn(graph, left, right_value, &computed)
+    } else {
+        panic!("Either side should give a concrete answer.");
+    }
+}
+
+fn p1(graph: &HashMap<&str, Job>) -> Answer {
+    evaluate(graph, "root").into()
+}
+
+fn p2(graph: &HashMap<&str, Job>) -> Answer {
+    find_humn(graph).into()
+}
+
+/// `size.width` monkeys shouting numbers, one of them the human, joined
+/// by operations so that every division is exact and both sides of root
+/// already match. The human's number is then the answer to part 2.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    /// Adds monkeys with `leaves` numbers between them that work out to
+    /// `value`, returning the name of the one on top. If `human` is set,
+    /// humn shouts one of the numbers.
+    fn build(
+        rng: &mut Rng,
+        jobs: &mut Vec<(String, String)>,
+        names: &mut HashSet<String>,
+        value: i64,
+        leaves: usize,
+        human: bool,
+    ) -> String {
+        let name = if human && leaves == 1 {
+            "humn".to_string()
+        } else {
+            loop {
+                let name = (0..4)
+                    .map(|_| (b'a' + rng.below(26) as u8) as char)
+                    .collect::<String>();
+                if name != "root" && name != "humn" && names.insert(name.clone()) {
+                    break name;
+                }
+            }
+        };
+        if leaves == 1 {
+            jobs.push((name.clone(), value.to_string()));
+            return name;
+        }
+
+        // the human never ends up in a divisor, which part 2 cannot undo
+        let (operator, left, right, human_left) = loop {
+            let human_left = rng.chance(0.5);
+            match rng.below(4) {
+                0 => {
+                    let right = rng.range(-100..=100);
+                    break ('+', value - right, right, human_left);
+                }
+                1 => {
+                    let right = rng.range(1..=100);
+                    break ('-', value + right, right, human_left);
+                }
+                2 if value != 0 => {
+                    let factors = (1..=20).filter(|f| value % f == 0).collect::<Vec<_>>();
+                    let right = *rng.pick(&factors);
+                    break ('*', value / right, right, human_left);
+                }
+                3 if value.abs() <= 1_000_000 => {
+                    let right = rng.range(1..=20);
+                    break ('/', value * right, right, true);
+                }
+                _ => continue,
+            }
+        };
+
+        let left_leaves = rng.range(1..=leaves as i64 - 1) as usize;
+        let left = build(rng, jobs, names, left, left_leaves, human && human_left);
+        let right = build(
+            rng,
+            jobs,
+            names,
+            right,
+            leaves - left_leaves,
+            human && !human_left,
+        );
+        jobs.push((name.clone(), format!("{} {} {}", left, operator, right)));
+        name
+    }
+
+    let (mut jobs, mut names) = (vec![], HashSet::new());
+    let leaves = size.width.max(2);
+    let left_leaves = rng.range(1..=leaves as i64 - 1) as usize;
+    let value = rng.range(1..=100_000);
+    let human_left = rng.chance(0.5);
+
+    let left = build(rng, &mut jobs, &mut names, value, left_leaves, human_left);
+    let right = build(
+        rng,
+        &mut jobs,
+        &mut names,
+        value,
+        leaves - left_leaves,
+        !human_left,
+    );
+    let operator = rng.pick(&['+', '-', '*', '/']);
+    jobs.push((
+        "root".to_string(),
+        format!("{} {} {}", left, operator, right),
+    ));
+
+    rng.shuffle(&mut jobs);
+    jobs.iter()
+        .map(|(name, job)| format!("{}: {}", name, job))
+        .join("\n")
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+    const SAMPLE: &'static str = include_str!("../bin/day21/sample.txt");
+
+    type Input<'a> = HashMap<&'a str, Job<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day21/sample.txt");
+
+    #[test]
+    fn test_p2_finds_generated_humn() {
+        rng::check(50, |rng| {
+            let input = generate(rng, Size::new(40, 1));
+            let humn = input
+                .lines()
+                .find_map(|line| line.strip_prefix("humn: "))
+                .unwrap();
+            assert_eq!(p2(&Day21::parse(&input).unwrap()), humn);
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day21::parse(SAMPLE_INPUT).unwrap()), "152");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day21>(Part::One);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day21::parse(&SAMPLE_INPUT.replace("root: pppw + sjmn", "root: pppw + sjmx"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(err.expected, "a monkey that is listed somewhere");
+
+        let err = Day21::parse(&SAMPLE_INPUT.replace("dbpl: 5", "dbpl: 5 +"))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 9));
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day21::parse(SAMPLE_INPUT).unwrap()), "301");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day21>(Part::Two);
+    }
+}
@@ -0,0 +1,1066 @@
+//! Day 22: Monkey Map.
+
+use itertools::Itertools;
+
+use crate::{
+    generate::Size,
+    grid::Grid,
+    parse::{self, Line, ParseError},
+    point::{Direction, Point2, Point3},
+    rng::Rng,
+    Answer, Solution,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Forward(i32),
+    Left,
+    Right,
+}
+
+impl Instruction {
+    /// Splits the path on `line` into steps and turns.
+    pub fn parse(line: &Line) -> Result<Vec<Self>, ParseError> {
+        let path = line.chars("a number of steps, L or R", |ch| {
+            matches!(ch, 'L' | 'R' | '0'..='9')
+        })?;
+        let mut insts = vec![];
+
+        // every piece is a number of steps, a turn, or both
+        for piece in path.split_inclusive(['L', 'R']) {
+            let steps = piece.trim_end_matches(['L', 'R']);
+            if !steps.is_empty() {
+                insts.push(Instruction::Forward(
+                    line.parse(steps, "a number of steps")?,
+                ));
+            }
+            match piece.chars().last() {
+                Some('L') => insts.push(Instruction::Left),
+                Some('R') => insts.push(Instruction::Right),
+                _ => {}
+            }
+        }
+
+        Ok(insts)
+    }
+}
+
+/// The map, with `' '` off the board, and the first and last tile on the
+/// board in every row and column.
+pub struct FloorPlan {
+    pub tiles: Grid<char>,
+    pub minmax_row: Vec<(usize, usize)>,
+    pub minmax_col: Vec<(usize, usize)>,
+}
+
+impl FloorPlan {
+    pub fn parse(lines: &str) -> Self {
+        let width = lines
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap();
+
+        // rows stop at their last tile, so pad them into a rectangle
+        let tiles = Grid::from_rows(
+            lines
+                .lines()
+                .map(|line| {
+                    let mut row = line.chars().collect::<Vec<_>>();
+                    row.resize(width, ' ');
+                    row
+                })
+                .collect(),
+        );
+
+        let minmax_row = tiles
+            .rows()
+            .map(|row| {
+                (
+                    row.iter().position(|ch| *ch != ' ').unwrap_or_default(),
+                    row.iter().rposition(|ch| *ch != ' ').unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        let minmax_col = (0..tiles.width())
+            .map(|col_idx| {
+                (
+                    tiles
+                        .column(col_idx)
+                        .position(|ch| *ch != ' ')
+                        .unwrap_or_default(),
+                    tiles
+                        .column(col_idx)
+                        .rposition(|ch| *ch != ' ')
+                        .unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        Self {
+            tiles,
+            minmax_row,
+            minmax_col,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Person {
+    pub position: Point2<usize>,
+    pub facing: Direction,
+}
+
+impl Person {
+    pub fn password(&self) -> usize {
+        let facing = match self.facing {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        (self.position.y + 1) * 1000 + (self.position.x + 1) * 4 + facing
+    }
+}
+
+impl Instruction {
+    /// Where `person` ends up after following this instruction, stopping
+    /// at walls, and going past the edge of the board as `wrap` says.
+    /// An edge that `wrap` leads nowhere from stops them like a wall.
+    pub fn execute(&self, floor_plan: &FloorPlan, wrap: &Wrap, person: &Person) -> Person {
+        match self {
+            Instruction::Left => Person {
+                facing: person.facing.turn_left(),
+                ..*person
+            },
+            Instruction::Right => Person {
+                facing: person.facing.turn_right(),
+                ..*person
+            },
+            Instruction::Forward(steps) => {
+                let mut current = person.clone();
+
+                (0..(*steps as usize)).find(|_| match wrap.step(floor_plan, &current) {
+                    Some(next) if floor_plan.tiles[next.position] != '#' => {
+                        current = next;
+                        false
+                    }
+                    _ => true,
+                });
+
+                current
+            }
+        }
+    }
+}
+
+/// Where walking off the edge of the board leads.
+pub enum Wrap {
+    /// Onto the opposite edge of the same row or column.
+    Flat,
+    /// Onto the edge it meets once the board is folded into a cube.
+    Cube(Cube),
+}
+
+impl Wrap {
+    /// One tile forward from `person`, walls or not, if there is a way on.
+    pub fn step(&self, floor_plan: &FloorPlan, person: &Person) -> Option<Person> {
+        let tiles = &floor_plan.tiles;
+        if let Some(next) = tiles
+            .offset(person.position, person.facing.delta())
+            .filter(|next| tiles[*next] != ' ')
+        {
+            return Some(Person {
+                position: next,
+                ..*person
+            });
+        }
+
+        match self {
+            Wrap::Flat => {
+                let current = person.position;
+                let (min_x, max_x) = floor_plan.minmax_row[current.y];
+                let (min_y, max_y) = floor_plan.minmax_col[current.x];
+                let position = match person.facing {
+                    Direction::Right => Point2::new(min_x, current.y),
+                    Direction::Down => Point2::new(current.x, min_y),
+                    Direction::Left => Point2::new(max_x, current.y),
+                    Direction::Up => Point2::new(current.x, max_y),
+                };
+                Some(Person {
+                    position,
+                    ..*person
+                })
+            }
+            Wrap::Cube(cube) => cube.cross(person),
+        }
+    }
+}
+
+/// A face of the cube: where it is on the board, and which way it and its
+/// rows and columns point once folded, as unit vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Face {
+    /// The top left tile of the face on the board.
+    pub corner: Point2<usize>,
+    /// Pointing out of the cube.
+    pub normal: Point3<i32>,
+    pub right: Point3<i32>,
+    pub down: Point3<i32>,
+}
+
+impl Face {
+    /// The way `facing` points on this face.
+    pub fn direction(&self, facing: Direction) -> Point3<i32> {
+        match facing {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Up => -self.down,
+        }
+    }
+
+    /// The face across the edge that `facing` points at, folded down
+    /// along that edge. Its `corner` is left for the caller to fill in.
+    fn fold(&self, facing: Direction) -> Self {
+        let (normal, right, down) = match facing {
+            Direction::Right => (self.right, -self.normal, self.down),
+            Direction::Left => (-self.right, self.normal, self.down),
+            Direction::Down => (self.down, self.right, -self.normal),
+            Direction::Up => (-self.down, self.right, self.normal),
+        };
+        Self {
+            normal,
+            right,
+            down,
+            ..*self
+        }
+    }
+
+    /// The centre of the tile at `position` on this face, on a cube that
+    /// spans -`side` to `side` on every axis, so that it stays whole.
+    fn centre(&self, side: usize, position: Point2<usize>) -> Point3<i32> {
+        let side = side as i32;
+        let x = (position.x - self.corner.x) as i32;
+        let y = (position.y - self.corner.y) as i32;
+        self.normal * side + self.right * (2 * x + 1 - side) + self.down * (2 * y + 1 - side)
+    }
+
+    /// The tile of this face whose centre is at `point`.
+    fn tile_at(&self, side: usize, point: Point3<i32>) -> Point2<usize> {
+        let dot = |axis: Point3<i32>| point.x * axis.x + point.y * axis.y + point.z * axis.z;
+        let side = side as i32;
+        Point2::new(
+            self.corner.x + ((dot(self.right) + side - 1) / 2) as usize,
+            self.corner.y + ((dot(self.down) + side - 1) / 2) as usize,
+        )
+    }
+}
+
+/// The board folded into a cube.
+#[derive(Debug, Clone)]
+pub struct Cube {
+    /// The number of tiles along an edge of the cube.
+    pub side: usize,
+    pub faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds any of the eleven cube nets, turned or flipped in any way,
+    /// with faces of any size. Gives up if the board is no such net.
+    pub fn fold(floor_plan: &FloorPlan) -> Option<Self> {
+        let tiles = &floor_plan.tiles;
+        let count = tiles.iter().filter(|(_, tile)| **tile != ' ').count();
+        let side = (1..).take_while(|side| 6 * side * side <= count).last()?;
+        if 6 * side * side != count {
+            return None;
+        }
+        let on_board = |corner: Point2<usize>| tiles.get(corner).is_some_and(|tile| *tile != ' ');
+
+        // starting from the top left face, fold every face reached on the
+        // board against the face it was reached from
+        let corner = (0..tiles.width())
+            .step_by(side)
+            .map(|x| Point2::new(x, 0))
+            .find(|corner| on_board(*corner))?;
+        let mut faces = vec![Face {
+            corner,
+            normal: Point3::new(0, 0, -1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        }];
+        let mut next = 0;
+        while let Some(face) = faces.get(next).copied() {
+            next += 1;
+            for facing in Direction::ALL {
+                let Some(corner) = face
+                    .corner
+                    .checked_add_signed(facing.delta::<isize>() * side as isize)
+                    .filter(|corner| on_board(*corner))
+                else {
+                    continue;
+                };
+                if faces.iter().all(|face| face.corner != corner) {
+                    faces.push(Face {
+                        corner,
+                        ..face.fold(facing)
+                    });
+                }
+            }
+        }
+
+        let whole = faces.iter().all(|face| {
+            (0..side)
+                .cartesian_product(0..side)
+                .all(|(x, y)| on_board(face.corner + Point2::new(x, y)))
+        });
+        let cube = faces.len() == 6 && faces.iter().map(|face| face.normal).all_unique();
+        (whole && cube).then_some(Self { side, faces })
+    }
+
+    fn face_at(&self, position: Point2<usize>) -> Option<&Face> {
+        self.faces.iter().find(|face| {
+            (face.corner.x..face.corner.x + self.side).contains(&position.x)
+                && (face.corner.y..face.corner.y + self.side).contains(&position.y)
+        })
+    }
+
+    /// Where `person` ends up after walking off the edge of their face,
+    /// which is onto the face that the edge folds against, facing away
+    /// from the face they came from. Only a `person` off the board has no
+    /// face to walk off.
+    pub fn cross(&self, person: &Person) -> Option<Person> {
+        let from = self.face_at(person.position)?;
+        let heading = from.direction(person.facing);
+        let to = self.faces.iter().find(|face| face.normal == heading)?;
+
+        // one tile along the heading, and one tile down over the edge
+        let point = from.centre(self.side, person.position) + heading - from.normal;
+        Some(Person {
+            position: to.tile_at(self.side, point),
+            facing: Direction::ALL
+                .into_iter()
+                .find(|facing| to.direction(*facing) == -from.normal)?,
+        })
+    }
+}
+
+pub struct Notes {
+    pub floor_plan: FloorPlan,
+    /// The map folded up, which part 2 walks over, if it folds into a cube.
+    pub cube: Option<Cube>,
+    pub instructions: Vec<Instruction>,
+}
+
+pub fn parse_input(input: &str) -> Result<Notes, ParseError> {
+    let blocks = parse::blocks(input);
+    let (map, path) = match blocks.as_slice() {
+        [map, path] => (map, path),
+        [] => return Err(ParseError::end_of_input(input, "the map")),
+        [_] => {
+            return Err(ParseError::end_of_input(
+                input,
+                "a blank line followed by the path",
+            ))
+        }
+        [_, _, extra, ..] => return Err(extra[0].error(extra[0].text, "end of input")),
+    };
+
+    for line in map {
+        line.chars("a tile (' ', '.' or '#')", |ch| {
+            matches!(ch, ' ' | '.' | '#')
+        })?;
+    }
+    if !map[0].text.contains('.') {
+        return Err(map[0].error(map[0].text, "an open tile to start from"));
+    }
+    if let [_, extra, ..] = path.as_slice() {
+        return Err(extra.error(extra.text, "end of input"));
+    }
+    let instructions = Instruction::parse(&path[0])?;
+
+    let floor_plan = FloorPlan::parse(&map.iter().map(|line| line.text).join("\n"));
+    let cube = Cube::fold(&floor_plan);
+
+    Ok(Notes {
+        floor_plan,
+        cube,
+        instructions,
+    })
+}
+
+/// Follows the path from the first tile of the top row, facing right.
+fn follow(notes: &Notes, wrap: &Wrap) -> Person {
+    let floor_plan = &notes.floor_plan;
+
+    notes.instructions.iter().fold(
+        Person {
+            position: Point2::new(floor_plan.minmax_row[0].0, 0),
+            facing: Direction::Right,
+        },
+        |person, inst| inst.execute(floor_plan, wrap, &person),
+    )
+}
+
+fn p1(notes: &Notes) -> Answer {
+    follow(notes, &Wrap::Flat).password().into()
+}
+
+fn p2(notes: &Notes) -> Answer {
+    match &notes.cube {
+        Some(cube) => follow(notes, &Wrap::Cube(cube.clone())).password().into(),
+        None => Answer::Unsolved("the map does not fold into a cube".to_string()),
+    }
+}
+
+/// The eleven ways to unfold a cube, as the faces on a grid of face-sized
+/// blocks. Any of them can also be turned or flipped.
+const NETS: [&[&str]; 11] = [
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+/// A cube with faces `size.width` tiles across, unfolded along a random
+/// net, and a path of `size.height` moves over it.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let side = size.width;
+    let mut net = rng
+        .pick(&NETS)
+        .iter()
+        .map(|row| row.chars().map(|block| block == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    if rng.chance(0.5) {
+        net = (0..net[0].len())
+            .map(|x| net.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+    if rng.chance(0.5) {
+        net.reverse();
+    }
+    if rng.chance(0.5) {
+        net.iter_mut().for_each(|row| row.reverse());
+    }
+
+    let mut tiles = (0..net.len() * side)
+        .map(|y| {
+            (0..net[0].len() * side)
+                .map(|x| match net[y / side][x / side] {
+                    false => ' ',
+                    true if rng.chance(0.1) => '#',
+                    true => '.',
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // the path starts on the first tile of the top row
+    if let Some(start) = tiles[0].iter_mut().find(|tile| **tile != ' ') {
+        *start = '.';
+    }
+
+    let steps = 4 * side as i64;
+    let mut path = rng.range(1..=steps).to_string();
+    for _ in 1..size.height {
+        path.push(*rng.pick(&['L', 'R']));
+        path += &rng.range(1..=steps).to_string();
+    }
+
+    let map = tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>().trim_end().to_string())
+        .join("\n");
+    format!("{}\n\n{}", map, path)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 22;
+    const SAMPLE: &'static str = include_str!("../bin/day22/sample.txt");
+
+    type Input<'a> = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day22/sample.txt");
+
+    fn instructions(path: &str) -> Vec<Instruction> {
+        Instruction::parse(&Line {
+            number: 1,
+            text: path,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        assert_eq!(
+            instructions("10R5L5R10L4R5L5"),
+            vec![
+                Instruction::Forward(10),
+                Instruction::Right,
+                Instruction::Forward(5),
+                Instruction::Left,
+                Instruction::Forward(5),
+                Instruction::Right,
+                Instruction::Forward(10),
+                Instruction::Left,
+                Instruction::Forward(4),
+                Instruction::Right,
+                Instruction::Forward(5),
+                Instruction::Left,
+                Instruction::Forward(5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_floorplan() {
+        let floor_plan = FloorPlan::parse(
+            r"        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.",
+        );
+        assert_eq!(
+            floor_plan.minmax_row,
+            vec![
+                (8, 11),
+                (8, 11),
+                (8, 11),
+                (8, 11),
+                (0, 11),
+                (0, 11),
+                (0, 11),
+                (0, 11),
+                (8, 15),
+                (8, 15),
+                (8, 15),
+                (8, 15),
+            ]
+        );
+        assert_eq!(
+            floor_plan.minmax_col,
+            vec![
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (4, 7),
+                (0, 11),
+                (0, 11),
+                (0, 11),
+                (0, 11),
+                (8, 11),
+                (8, 11),
+                (8, 11),
+                (8, 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_steps_horizontal() {
+        let floor = FloorPlan::parse(".....");
+        let insts = instructions("RL");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Right,
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Down
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Up,
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Right
+            }
+        );
+        assert_eq!(
+            insts[1].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Right,
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Up
+            }
+        );
+        assert_eq!(
+            insts[1].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Up,
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Left
+            }
+        );
+
+        let insts = instructions("2");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Right
+                }
+            ),
+            Person {
+                position: Point2::new(2, 0),
+                facing: Direction::Right
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(4, 0),
+                    facing: Direction::Right
+                }
+            ),
+            Person {
+                position: Point2::new(1, 0),
+                facing: Direction::Right
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Left
+                }
+            ),
+            Person {
+                position: Point2::new(3, 0),
+                facing: Direction::Left
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(4, 0),
+                    facing: Direction::Left
+                }
+            ),
+            Person {
+                position: Point2::new(2, 0),
+                facing: Direction::Left
+            }
+        );
+
+        let floor = FloorPlan::parse("#..#...");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(1, 0),
+                    facing: Direction::Right
+                }
+            ),
+            Person {
+                position: Point2::new(2, 0),
+                facing: Direction::Right
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(2, 0),
+                    facing: Direction::Right
+                }
+            ),
+            Person {
+                position: Point2::new(2, 0),
+                facing: Direction::Right
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(6, 0),
+                    facing: Direction::Right
+                }
+            ),
+            Person {
+                position: Point2::new(6, 0),
+                facing: Direction::Right
+            }
+        );
+
+        let floor = FloorPlan::parse("...#..#");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(5, 0),
+                    facing: Direction::Left
+                }
+            ),
+            Person {
+                position: Point2::new(4, 0),
+                facing: Direction::Left
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(4, 0),
+                    facing: Direction::Left
+                }
+            ),
+            Person {
+                position: Point2::new(4, 0),
+                facing: Direction::Left
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Left
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Left
+            }
+        );
+    }
+
+    #[test]
+    fn test_steps_vertical() {
+        let floor = FloorPlan::parse(".\n.\n.\n.\n.");
+        let insts = instructions("2");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Down
+                }
+            ),
+            Person {
+                position: Point2::new(0, 2),
+                facing: Direction::Down
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 4),
+                    facing: Direction::Down
+                }
+            ),
+            Person {
+                position: Point2::new(0, 1),
+                facing: Direction::Down
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Up
+                }
+            ),
+            Person {
+                position: Point2::new(0, 3),
+                facing: Direction::Up
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 4),
+                    facing: Direction::Up
+                }
+            ),
+            Person {
+                position: Point2::new(0, 2),
+                facing: Direction::Up
+            }
+        );
+
+        // TODO: change
+
+        let floor = FloorPlan::parse("#\n.\n.\n#\n.\n.\n.");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 1),
+                    facing: Direction::Down
+                }
+            ),
+            Person {
+                position: Point2::new(0, 2),
+                facing: Direction::Down
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 2),
+                    facing: Direction::Down
+                }
+            ),
+            Person {
+                position: Point2::new(0, 2),
+                facing: Direction::Down
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 6),
+                    facing: Direction::Down
+                }
+            ),
+            Person {
+                position: Point2::new(0, 6),
+                facing: Direction::Down
+            }
+        );
+
+        let floor = FloorPlan::parse(".\n.\n.\n#\n.\n.\n#");
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 5),
+                    facing: Direction::Up
+                }
+            ),
+            Person {
+                position: Point2::new(0, 4),
+                facing: Direction::Up
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 4),
+                    facing: Direction::Up
+                }
+            ),
+            Person {
+                position: Point2::new(0, 4),
+                facing: Direction::Up
+            }
+        );
+        assert_eq!(
+            insts[0].execute(
+                &floor,
+                &Wrap::Flat,
+                &Person {
+                    position: Point2::new(0, 0),
+                    facing: Direction::Up
+                }
+            ),
+            Person {
+                position: Point2::new(0, 0),
+                facing: Direction::Up
+            }
+        );
+    }
+
+    #[test]
+    fn test_fold_every_net() {
+        rng::check(50, |rng| {
+            let side = rng.range(1..=5) as usize;
+            let input = generate(rng, Size::new(side, 1)).replace('#', ".");
+            let Notes {
+                floor_plan, cube, ..
+            } = Day22::parse(&input).unwrap();
+            let cube = cube.unwrap();
+            assert_eq!(cube.side, side);
+            let wrap = Wrap::Cube(cube);
+
+            let tiles = floor_plan
+                .tiles
+                .iter()
+                .filter(|(_, tile)| **tile != ' ')
+                .collect::<Vec<_>>();
+            let person = Person {
+                position: rng.pick(&tiles).0,
+                facing: *rng.pick(&Direction::ALL),
+            };
+
+            // going straight around the cube leads back to the start
+            let around = Instruction::Forward(4 * side as i32);
+            assert_eq!(around.execute(&floor_plan, &wrap, &person), person);
+
+            // and so does turning back after any number of steps
+            let steps = Instruction::Forward(rng.range(1..=4 * side as i64) as i32);
+            let back = [steps, Instruction::Left, Instruction::Left]
+                .iter()
+                .cycle()
+                .take(6)
+                .fold(person.clone(), |person, inst| {
+                    inst.execute(&floor_plan, &wrap, &person)
+                });
+            assert_eq!(back, person);
+        });
+    }
+
+    #[test]
+    fn test_password() {
+        assert_eq!(
+            Person {
+                position: Point2::new(7, 5),
+                facing: Direction::Right
+            }
+            .password(),
+            6032
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day22::parse("").err().unwrap();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("the map", "end of input")
+        );
+
+        let err = Day22::parse(&format!("{}\n\n10R5\n", SAMPLE_INPUT.trim_end()))
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.expected.as_str()), (16, "end of input"));
+
+        let err = Day22::parse(&SAMPLE_INPUT.replace("10R5L5R10L4R5L5", "10R99999999999"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (14, 4, "a number of steps")
+        );
+    }
+
+    #[test]
+    fn test_flat_map() {
+        // a board of the right size that does not fold
+        let notes = Day22::parse("......\n\n10R5\n").unwrap();
+        assert_eq!(p1(&notes), "1021");
+        assert_eq!(
+            p2(&notes).unsolved(),
+            Some("the map does not fold into a cube")
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day22::parse(SAMPLE_INPUT).unwrap()), "6032");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day22>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day22::parse(SAMPLE_INPUT).unwrap()), "5031");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day22>(Part::Two);
+    }
+}
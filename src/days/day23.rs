@@ -0,0 +1,323 @@
+//! Day 23: Unstable Diffusion.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generate::Size,
+    grid::Grid,
+    parse::ParseError,
+    point::{Direction, Point2},
+    rng::Rng,
+    visualize::{plot, Recorder},
+    Answer, Solution,
+};
+
+/// The elves' positions, and the number of rounds played so far.
+#[derive(Clone)]
+pub struct World {
+    pub elves: HashSet<Point2>,
+    pub round: usize,
+}
+
+/// The directions an elf considers, in the order of the first round.
+const PROPOSALS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+impl World {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "an elf (#) or ground (.)", |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let elves = grid
+            .iter()
+            .filter(|(_, elf)| **elf)
+            .map(|(pos, _)| Point2::new(pos.x as i32, pos.y as i32))
+            .collect::<HashSet<_>>();
+        if elves.is_empty() {
+            return Err(ParseError::end_of_input(input, "an elf (#)"));
+        }
+
+        Ok(Self { elves, round: 0 })
+    }
+
+    pub fn any_adjacent(&self, elf: Point2) -> bool {
+        elf.neighbours8().any(|coord| self.elves.contains(&coord))
+    }
+
+    /// Whether the three tiles ahead of `elf` in `direction` are free.
+    pub fn can_move(&self, elf: Point2, direction: Direction) -> bool {
+        let ahead = elf + direction.delta();
+        [
+            ahead,
+            ahead + direction.turn_left().delta(),
+            ahead + direction.turn_right().delta(),
+        ]
+        .into_iter()
+        .all(|coord| !self.elves.contains(&coord))
+    }
+
+    /// Plays a round, returning the number of elves that moved.
+    pub fn next_round(&mut self) -> usize {
+        let mut proposals: HashMap<Point2, Vec<Point2>> = HashMap::new();
+
+        self.elves
+            .iter()
+            .filter(|elf| self.any_adjacent(**elf))
+            .for_each(|elf| {
+                if let Some(direction) = (0..4)
+                    .map(|n| PROPOSALS[(n + self.round) % 4])
+                    .find(|direction| self.can_move(*elf, *direction))
+                {
+                    proposals
+                        .entry(*elf + direction.delta())
+                        .or_default()
+                        .push(*elf);
+                }
+            });
+
+        let mut count = 0;
+
+        proposals
+            .into_iter()
+            .filter(|(_, elves)| elves.len() == 1)
+            .for_each(|(coord, elves)| {
+                self.elves.remove(&elves[0]);
+                self.elves.insert(coord);
+                count += 1;
+            });
+
+        self.round += 1;
+        count
+    }
+
+    /// The empty ground within the smallest rectangle holding every elf.
+    pub fn empty_tiles(&self) -> i32 {
+        let width = self.elves.iter().map(|elf| elf.x).max().unwrap()
+            - self.elves.iter().map(|elf| elf.x).min().unwrap()
+            + 1;
+        let height = self.elves.iter().map(|elf| elf.y).max().unwrap()
+            - self.elves.iter().map(|elf| elf.y).min().unwrap()
+            + 1;
+        width * height - self.elves.len() as i32
+    }
+}
+
+fn p1(world: &World) -> Answer {
+    let mut world = world.clone();
+
+    (0..10).for_each(|_| {
+        world.next_round();
+    });
+
+    world.empty_tiles().into()
+}
+
+fn p2(world: &World) -> Answer {
+    let mut world = world.clone();
+
+    while world.next_round() > 0 {}
+
+    world.round.into()
+}
+
+/// Spreads the elves out as in part 2, drawing a frame for every round.
+fn visualize(world: &World, recorder: &mut Recorder) {
+    let mut world = world.clone();
+    let frame = |world: &World| plot(world.elves.iter().map(|elf| (*elf, '#')), '.');
+
+    let mut more = recorder.record(&frame(&world));
+    while more && world.next_round() > 0 {
+        more = recorder.record(&frame(&world));
+    }
+}
+
+/// A `size.width`x`size.height` scan with elves on about a third of it.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let mut scan = Grid::from_rows(
+        (0..size.height)
+            .map(|_| {
+                (0..size.width)
+                    .map(|_| if rng.chance(0.3) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect(),
+    );
+    // there has to be someone to move around
+    let elf = Point2::new(rng.index(size.width), rng.index(size.height));
+    scan[elf] = '#';
+
+    scan.to_string()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+    const SAMPLE: &'static str = include_str!("../bin/day23/sample.txt");
+
+    type Input<'a> = World;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        World::from_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn visualize(input: &Self::Input<'_>, recorder: &mut Recorder) -> bool {
+        visualize(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day23/sample.txt");
+
+    const SMALLER_EXAMPLE: &str = r"
+.....
+..##.
+..#..
+.....
+..##.
+.....
+";
+    #[test]
+    fn test_parse() {
+        let world = World::from_input(SMALLER_EXAMPLE).unwrap();
+        assert_eq!(
+            world.elves,
+            HashSet::from([(2, 1), (3, 1), (2, 2), (2, 4), (3, 4)].map(Point2::from))
+        );
+
+        for input in ["", "...\n..."] {
+            let err = World::from_input(input).err().unwrap();
+            assert_eq!(err.expected, "an elf (#)");
+            assert_eq!(err.found, "end of input");
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let mut world = World::from_input(SMALLER_EXAMPLE).unwrap();
+
+        [
+            r"
+..##.
+.....
+..#..
+...#.
+..#..
+.....
+",
+            r"
+.....
+..##.
+.#...
+....#
+.....
+..#..
+",
+            r"
+..#..
+....#
+#....
+....#
+.....
+..#..
+
+",
+            r"
+..#..
+....#
+#....
+....#
+.....
+..#..
+
+",
+        ]
+        .into_iter()
+        .enumerate()
+        .for_each(|(index, expected)| {
+            let expected_world = World::from_input(expected).unwrap();
+
+            world.next_round();
+            assert_eq!(
+                world.elves,
+                expected_world.elves,
+                "Round {} elves",
+                index + 1
+            );
+            assert_eq!(world.round, index + 1, "Round {} round number", index + 1);
+        });
+    }
+
+    #[test]
+    fn test_rounds_move_elves_one_step() {
+        rng::check(30, |rng| {
+            let size = Size::new(rng.index(10) + 1, rng.index(10) + 1);
+            let start = Day23::parse(&generate(rng, size)).unwrap();
+            let mut world = start.clone();
+
+            loop {
+                let before = world.elves.clone();
+                let moved = world.next_round();
+                // no elf is lost, and those that moved took one step to a
+                // tile that nobody stood on
+                let arrived = world.elves.difference(&before).collect::<Vec<_>>();
+                assert_eq!(world.elves.len(), before.len());
+                assert_eq!(arrived.len(), moved);
+                assert!(arrived.iter().all(|elf| {
+                    Direction::ALL
+                        .iter()
+                        .any(|direction| before.contains(&(**elf - direction.delta())))
+                }));
+                if moved == 0 {
+                    break;
+                }
+            }
+            assert_eq!(p2(&start), world.round.to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day23::parse(SAMPLE_INPUT).unwrap()), "110");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day23>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day23::parse(SAMPLE_INPUT).unwrap()), "20");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day23>(Part::Two);
+    }
+}
@@ -0,0 +1,747 @@
+//! Day 24: Blizzard Basin. A [`World`] is the valley at one minute, and
+//! the [`Universe`] strings the minutes together for [`find_route`].
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    generate::Size,
+    grid::Grid,
+    parse::{self, ParseError},
+    point::{Direction, Point2},
+    rng::Rng,
+    search,
+    visualize::Recorder,
+    Answer, Solution,
+};
+
+pub type Coord = Point2<usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blizzard {
+    pub position: Coord,
+    pub direction: Direction,
+}
+
+/// The valley at one minute. `Display` draws it the way the input does.
+pub struct World {
+    size: Coord,
+    blizzards: Vec<Blizzard>,
+    blizzards_pos_cache: HashSet<Coord>,
+    start_position: Coord,
+    end_position: Coord,
+}
+
+impl std::fmt::Display for World {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let blizzards_cache: HashMap<&Coord, Vec<Direction>> =
+            self.blizzards
+                .iter()
+                .fold(HashMap::new(), |mut acc, entry| {
+                    acc.entry(&entry.position)
+                        .or_default()
+                        .push(entry.direction);
+                    acc
+                });
+
+        writeln!(
+            f,
+            "{}.{}",
+            "#".repeat(self.start_position.x),
+            "#".repeat(self.size.x - self.start_position.x - 1)
+        )?;
+        (1..(self.size.y - 1))
+            .map(|y| {
+                write!(f, "#")?;
+                (1..(self.size.x - 1))
+                    .map(|x| match blizzards_cache.get(&Point2 { x, y }) {
+                        Some(collection) => match collection.len() {
+                            1 => write!(
+                                f,
+                                "{}",
+                                match collection[0] {
+                                    Direction::Up => "^",
+                                    Direction::Down => "v",
+                                    Direction::Left => "<",
+                                    Direction::Right => ">",
+                                }
+                            ),
+                            _ => write!(f, "{}", collection.len()),
+                        },
+                        None => write!(f, "."),
+                    })
+                    .find(|res| res.is_err())
+                    .unwrap_or(Ok(()))?;
+                writeln!(f, "#")
+            })
+            .find(|res| res.is_err())
+            .unwrap_or(Ok(()))?;
+        writeln!(
+            f,
+            "{}.{}",
+            "#".repeat(self.end_position.x),
+            "#".repeat(self.size.x - self.end_position.x - 1)
+        )?;
+        Ok(())
+    }
+}
+
+impl World {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "a wall, ground or blizzard (#.<>^v)", |ch| {
+            matches!(ch, '#' | '.' | '<' | '>' | '^' | 'v').then_some(ch)
+        })?;
+        let lines = parse::lines(input).collect::<Vec<_>>();
+
+        if grid.height() < 3 || grid.width() < 3 {
+            return Err(ParseError::end_of_input(input, "a valley of at least 3x3"));
+        }
+        let size = Point2 {
+            x: grid.width(),
+            y: grid.height(),
+        };
+
+        let blizzards = grid
+            .iter()
+            .filter(|(_, ch)| *ch != &'.' && *ch != &'#')
+            .map(|(position, ch)| {
+                let direction = match ch {
+                    '>' => Direction::Right,
+                    '<' => Direction::Left,
+                    '^' => Direction::Up,
+                    'v' => Direction::Down,
+                    _ => unreachable!("checked by Grid::parse"),
+                };
+                (position, direction)
+            })
+            .map(|(position, direction)| {
+                if position.x == 0
+                    || position.y == 0
+                    || position.x == size.x - 1
+                    || position.y == size.y - 1
+                {
+                    let line = lines[position.y];
+                    return Err(line.error(
+                        &line.text[position.x..position.x + 1],
+                        "a blizzard inside the walls",
+                    ));
+                }
+                Ok(Blizzard {
+                    position,
+                    direction,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let blizzards_pos_cache =
+            HashSet::from_iter(blizzards.iter().map(|blizzard| blizzard.position));
+
+        let opening = |y: usize| {
+            grid.row(y).iter().position(|ch| ch == &'.').ok_or_else(|| {
+                let line = lines[y];
+                line.error(line.text, "an opening (.) in the wall")
+            })
+        };
+
+        let start_position = Point2 {
+            x: opening(0)?,
+            y: 0,
+        };
+
+        let end_position = Point2 {
+            x: opening(size.y - 1)?,
+            y: size.y - 1,
+        };
+
+        Ok(Self {
+            size,
+            blizzards,
+            blizzards_pos_cache,
+            start_position,
+            end_position,
+        })
+    }
+
+    /// The width and height of the valley, walls included.
+    pub fn size(&self) -> Coord {
+        self.size
+    }
+
+    pub fn blizzards(&self) -> &[Blizzard] {
+        &self.blizzards
+    }
+
+    pub fn has_blizzard(&self, coord: &Coord) -> bool {
+        self.blizzards_pos_cache.contains(coord)
+    }
+
+    pub fn start_position(&self) -> Coord {
+        self.start_position
+    }
+
+    pub fn end_position(&self) -> Coord {
+        self.end_position
+    }
+
+    /// The valley a minute later, with every blizzard moved one tile and
+    /// wrapped around to the opposite wall.
+    pub fn step(&self) -> Self {
+        let blizzards = self
+            .blizzards
+            .iter()
+            .map(|blizzard| Blizzard {
+                position: match blizzard.direction {
+                    Direction::Up => Point2 {
+                        y: if blizzard.position.y == 1 {
+                            self.size.y - 2
+                        } else {
+                            blizzard.position.y - 1
+                        },
+                        ..blizzard.position
+                    },
+                    Direction::Down => Point2 {
+                        y: if blizzard.position.y == self.size.y - 2 {
+                            1
+                        } else {
+                            blizzard.position.y + 1
+                        },
+                        ..blizzard.position
+                    },
+                    Direction::Left => Point2 {
+                        x: if blizzard.position.x == 1 {
+                            self.size.x - 2
+                        } else {
+                            blizzard.position.x - 1
+                        },
+                        ..blizzard.position
+                    },
+                    Direction::Right => Point2 {
+                        x: if blizzard.position.x == self.size.x - 2 {
+                            1
+                        } else {
+                            blizzard.position.x + 1
+                        },
+                        ..blizzard.position
+                    },
+                },
+                ..*blizzard
+            })
+            .collect::<Vec<_>>();
+
+        let blizzards_pos_cache =
+            HashSet::from_iter(blizzards.iter().map(|blizzard| blizzard.position));
+
+        Self {
+            blizzards,
+            blizzards_pos_cache,
+            ..*self
+        }
+    }
+}
+
+/// The blizzard timeline, simulated lazily and kept around so that every
+/// search, including the later trips of part 2, reuses the minutes already
+/// computed.
+pub struct Universe {
+    worlds: RefCell<Vec<World>>,
+    size: Coord,
+    start_position: Coord,
+    end_position: Coord,
+}
+
+impl Universe {
+    pub fn new(world: World) -> Self {
+        Self {
+            size: world.size,
+            start_position: world.start_position,
+            end_position: world.end_position,
+            worlds: RefCell::new(vec![world]),
+        }
+    }
+
+    pub fn start_position(&self) -> Coord {
+        self.start_position
+    }
+
+    pub fn end_position(&self) -> Coord {
+        self.end_position
+    }
+
+    fn simulate_universe_until(&self, time: usize) {
+        while self.worlds.borrow().len() < time + 1 {
+            let next_world = self.worlds.borrow().iter().last().unwrap().step();
+            self.worlds.borrow_mut().push(next_world);
+        }
+    }
+
+    /// Whether `coord` is open ground, free of blizzards at minute `time`.
+    pub fn is_valid_coord_at_time(&self, coord: &Coord, time: usize) -> bool {
+        if coord == &self.start_position || coord == &self.end_position {
+            return true;
+        }
+
+        if coord.x == 0 || coord.x >= self.size.x - 1 || coord.y == 0 || coord.y >= self.size.y - 1
+        {
+            return false;
+        }
+
+        self.simulate_universe_until(time);
+
+        !self.worlds.borrow().get(time).unwrap().has_blizzard(coord)
+    }
+
+    /// Where the expedition at `coord` can be a minute after
+    /// `current_time`, waiting included.
+    pub fn next_steps(&self, coord: &Coord, current_time: usize) -> Vec<Coord> {
+        vec![
+            Point2 {
+                x: coord.x - 1,
+                ..*coord
+            },
+            Point2 {
+                x: coord.x + 1,
+                ..*coord
+            },
+            Point2 {
+                // underflow can happen at start_position
+                y: if coord.y == 0 { 0 } else { coord.y - 1 },
+                ..*coord
+            },
+            Point2 {
+                y: coord.y + 1,
+                ..*coord
+            },
+            // waiting is a valid move
+            *coord,
+        ]
+        .into_iter()
+        .filter(|coord| self.is_valid_coord_at_time(coord, current_time + 1))
+        .collect()
+    }
+}
+
+/// The quickest way from `source` to `destination` when setting off at
+/// `start_time`, as the position at each minute along the way.
+pub fn find_route(
+    universe: &Universe,
+    start_time: usize,
+    source: Coord,
+    destination: Coord,
+) -> Vec<(Coord, usize)> {
+    // the same spot is a different state at every minute, since the
+    // blizzards around it move on
+    search::bfs(
+        [(source, start_time)],
+        |(coord, time)| {
+            universe
+                .next_steps(coord, *time)
+                .into_iter()
+                .map(|next| (next, time + 1))
+                .collect::<Vec<_>>()
+        },
+        |(coord, _)| *coord == destination,
+    )
+    .expect("We never run out of moves, we only have too much moves.")
+    .nodes
+}
+
+/// The minute of arrival at `destination`, see [`find_route`].
+pub fn find_shortest(
+    universe: &Universe,
+    start_time: usize,
+    source: Coord,
+    destination: Coord,
+) -> usize {
+    let (_, arrival) = *find_route(universe, start_time, source, destination)
+        .last()
+        .unwrap();
+    arrival
+}
+
+fn p1(universe: &Universe) -> Answer {
+    find_shortest(universe, 0, universe.start_position, universe.end_position).into()
+}
+
+fn p2(universe: &Universe) -> Answer {
+    let first = find_shortest(universe, 0, universe.start_position, universe.end_position);
+
+    let second = find_shortest(
+        universe,
+        first,
+        universe.end_position,
+        universe.start_position,
+    );
+    find_shortest(
+        universe,
+        second,
+        universe.start_position,
+        universe.end_position,
+    )
+    .into()
+}
+
+/// Follows the expedition (`E`) along the route of part 1.
+fn visualize(universe: &Universe, recorder: &mut Recorder) {
+    let route = find_route(universe, 0, universe.start_position, universe.end_position);
+
+    for (position, time) in route {
+        universe.simulate_universe_until(time);
+        let mut frame = Grid::from_rows(
+            universe.worlds.borrow()[time]
+                .to_string()
+                .lines()
+                .map(|line| line.chars().collect())
+                .collect(),
+        );
+        frame[position] = 'E';
+
+        if !recorder.record(&frame) {
+            break;
+        }
+    }
+}
+
+/// A valley `size.width`x`size.height` inside its walls, with blizzards on
+/// about a quarter of it. A route there, back and there again is planned
+/// first and kept clear of blizzards, so that both parts have an answer.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let (width, height) = (size.width, size.height);
+    let start = Point2::new(1, 0);
+    let end = Point2::new(width, height + 1);
+
+    // where the expedition is at every minute, lingering at random
+    let mut route = vec![start];
+    for (from, to) in [(start, end), (end, start), (start, end)] {
+        let first = Point2::new(from.x, from.y.clamp(1, height));
+        let last = Point2::new(to.x, to.y.clamp(1, height));
+        let toward = |from: usize, to: usize| if to > from { from + 1 } else { from - 1 };
+
+        route.extend(std::iter::repeat_n(from, rng.range(0..=3) as usize));
+        let mut position = first;
+        route.push(position);
+        while position != last {
+            if !rng.chance(0.2) {
+                if position.x != last.x && (position.y == last.y || rng.chance(0.5)) {
+                    position.x = toward(position.x, last.x);
+                } else {
+                    position.y = toward(position.y, last.y);
+                }
+            }
+            route.push(position);
+        }
+        route.push(to);
+    }
+
+    // the route through each row and column, as (position along it, minute)
+    let mut by_row = HashMap::<usize, Vec<(usize, usize)>>::new();
+    let mut by_column = HashMap::<usize, Vec<(usize, usize)>>::new();
+    for (minute, position) in route.iter().enumerate() {
+        by_row
+            .entry(position.y)
+            .or_default()
+            .push((position.x, minute));
+        by_column
+            .entry(position.x)
+            .or_default()
+            .push((position.y, minute));
+    }
+    // where a blizzard starting at `from` is after `minute` minutes
+    let blown = |from: usize, delta: i64, minute: usize, len: usize| {
+        1 + (from as i64 - 1 + delta * minute as i64).rem_euclid(len as i64) as usize
+    };
+
+    let mut valley = Grid::new(width + 2, height + 2, '#');
+    valley[start] = '.';
+    valley[end] = '.';
+    for y in 1..=height {
+        for x in 1..=width {
+            let blizzard = *rng.pick(&['<', '>', '^', 'v']);
+            let hits_route = match blizzard {
+                '<' | '>' => {
+                    let delta = if blizzard == '<' { -1 } else { 1 };
+                    by_row
+                        .get(&y)
+                        .into_iter()
+                        .flatten()
+                        .any(|(route_x, minute)| blown(x, delta, *minute, width) == *route_x)
+                }
+                _ => {
+                    let delta = if blizzard == '^' { -1 } else { 1 };
+                    by_column
+                        .get(&x)
+                        .into_iter()
+                        .flatten()
+                        .any(|(route_y, minute)| blown(y, delta, *minute, height) == *route_y)
+                }
+            };
+            valley[Point2::new(x, y)] = if rng.chance(0.25) && !hits_route {
+                blizzard
+            } else {
+                '.'
+            };
+        }
+    }
+
+    valley.to_string()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 24;
+    const SAMPLE: &'static str = include_str!("../bin/day24/sample.txt");
+
+    type Input<'a> = Universe;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        World::from_input(input).map(Universe::new)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn visualize(input: &Self::Input<'_>, recorder: &mut Recorder) -> bool {
+        visualize(input, recorder);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day24/sample.txt");
+
+    const QUESTION_EXAMPLE: &str = r"
+#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#
+";
+    const MY_EXAMPLE: &str = r"
+#.###
+#.<.#
+#..^#
+##.##
+";
+
+    #[test]
+    fn test_parse_input() {
+        // question example
+        assert_eq!(
+            World::from_input(QUESTION_EXAMPLE)
+                .unwrap()
+                .to_string()
+                .trim(),
+            QUESTION_EXAMPLE.trim()
+        );
+
+        // my example
+        assert_eq!(
+            World::from_input(MY_EXAMPLE).unwrap().to_string().trim(),
+            MY_EXAMPLE.trim()
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        // question example
+        let mut world = World::from_input(QUESTION_EXAMPLE).unwrap();
+
+        vec![
+            r"
+#.#####
+#.....#
+#.>...#
+#.....#
+#.....#
+#...v.#
+#####.#
+",
+            r"
+#.#####
+#...v.#
+#..>..#
+#.....#
+#.....#
+#.....#
+#####.#
+",
+            r"
+#.#####
+#.....#
+#...2.#
+#.....#
+#.....#
+#.....#
+#####.#
+",
+            r"
+#.#####
+#.....#
+#....>#
+#...v.#
+#.....#
+#.....#
+#####.#
+",
+            r"
+#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#.....#
+#####.#
+",
+        ]
+        .into_iter()
+        .for_each(|state| {
+            world = world.step();
+
+            assert_eq!(world.to_string().trim(), state.trim());
+        });
+
+        // my example
+        let mut world = World::from_input(MY_EXAMPLE).unwrap();
+        vec![
+            r"
+#.###
+#<.^#
+#...#
+##.##
+",
+            r"
+#.###
+#..<#
+#..^#
+##.##
+",
+            r"
+#.###
+#.<^#
+#...#
+##.##
+",
+            r"
+#.###
+#<..#
+#..^#
+##.##
+",
+            r"
+#.###
+#..2#
+#...#
+##.##
+",
+            r"
+#.###
+#.<.#
+#..^#
+##.##
+",
+        ]
+        .into_iter()
+        .for_each(|state| {
+            world = world.step();
+
+            assert_eq!(world.to_string().trim(), state.trim());
+        });
+    }
+
+    #[test]
+    fn test_universe() {
+        let universe = Universe::new(World::from_input(SAMPLE_INPUT).unwrap());
+        universe.simulate_universe_until(18);
+        assert_eq!(
+            universe.worlds.borrow().get(18).unwrap().to_string().trim(),
+            r"
+#.######
+#>2.<.<#
+#.2v^2<#
+#>..>2>#
+#<....>#
+######.#
+"
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_timeline_shared_between_parts() {
+        let universe = Day24::parse(SAMPLE_INPUT).unwrap();
+
+        Day24::part1(&universe);
+        let simulated = universe.worlds.borrow().len();
+        assert!(simulated > 18);
+
+        Day24::part2(&universe);
+        assert!(universe.worlds.borrow().len() > simulated);
+        assert!(universe.worlds.borrow().len() <= 54 + 2);
+    }
+
+    #[test]
+    fn test_routes_are_walkable() {
+        rng::check(30, |rng| {
+            let size = Size::new(rng.index(8) + 1, rng.index(6) + 1);
+            let universe = Day24::parse(&generate(rng, size)).unwrap();
+            let (start, end) = (universe.start_position(), universe.end_position());
+
+            let route = find_route(&universe, 0, start, end);
+            assert_eq!(route[0], (start, 0));
+            for pair in route.windows(2) {
+                let ((from, time), (to, next)) = (pair[0], pair[1]);
+                assert_eq!(next, time + 1);
+                assert!(from.manhattan(to) <= 1);
+                assert!(universe.is_valid_coord_at_time(&to, next));
+            }
+            let (last, there) = *route.last().unwrap();
+            assert_eq!(last, end);
+            assert!(there >= start.manhattan(end));
+
+            // each trip of part 2 takes at least as long as the walk
+            let back = find_shortest(&universe, there, end, start);
+            let again = find_shortest(&universe, back, start, end);
+            assert!(back >= there + start.manhattan(end));
+            assert!(again >= back + start.manhattan(end));
+            assert_eq!(p2(&universe), again.to_string().as_str());
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day24::parse(SAMPLE_INPUT).unwrap()), "18");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day24>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day24::parse(SAMPLE_INPUT).unwrap()), "54");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day24>(Part::Two);
+    }
+}
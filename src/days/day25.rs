@@ -0,0 +1,177 @@
+//! Day 25: Full of Hot Air. SNAFU numbers are written in balanced base 5,
+//! with the digits `=`, `-`, `0`, `1` and `2` worth -2 to 2.
+
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+use crate::{
+    generate::Size,
+    parse::{self, ParseError},
+    rng::Rng,
+    Answer, Solution,
+};
+
+/// Panics on anything but SNAFU digits.
+pub fn from_snafu(value: &str) -> i64 {
+    value.chars().fold(0, |acc, ch| {
+        acc * 5
+            + match ch {
+                '0' => 0,
+                '1' => 1,
+                '2' => 2,
+                '-' => -1,
+                '=' => -2,
+                _ => panic!("Invalid digit {}", ch),
+            }
+    })
+}
+
+/// Writes a number in SNAFU. A negative number is its magnitude with every
+/// digit negated.
+pub fn to_snafu(value: i64) -> String {
+    match value.cmp(&0) {
+        Ordering::Less => to_snafu_magnitude(value.unsigned_abs())
+            .chars()
+            .map(|ch| match ch {
+                '2' => '=',
+                '1' => '-',
+                '-' => '1',
+                '=' => '2',
+                _ => ch,
+            })
+            .collect(),
+        Ordering::Equal => "0".to_string(),
+        Ordering::Greater => to_snafu_magnitude(value.unsigned_abs()),
+    }
+}
+
+fn to_snafu_magnitude(mut value: u64) -> String {
+    let mut stack = vec![];
+    let mut carry = 0;
+
+    while value > 0 {
+        let current_digit = value % 5;
+        value /= 5;
+
+        let final_value = carry + current_digit;
+        carry = (final_value >= 3).into();
+
+        stack.push(match final_value {
+            0..=2 => (final_value as u8 + b'0') as char,
+            3 => '=',
+            4 => '-',
+            5 => '0',
+            _ => unreachable!("Value cannot exceed [0-5]"),
+        });
+    }
+
+    if carry == 1 {
+        stack.push('1');
+    }
+
+    stack.iter().rev().collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::each_line(input, "a SNAFU number", |line| {
+        line.chars("a SNAFU digit (2, 1, 0, - or =)", |ch| {
+            matches!(ch, '2' | '1' | '0' | '-' | '=')
+        })
+        .map(from_snafu)
+    })
+}
+
+fn p1(numbers: &[i64]) -> Answer {
+    to_snafu(numbers.iter().sum()).into()
+}
+
+fn p2(_numbers: &[i64]) -> Answer {
+    "".into()
+}
+
+/// `size.width` fuel requirements of up to `size.height` SNAFU digits, and
+/// no more than 15 so that the sum stays in range.
+fn generate(rng: &mut Rng, size: Size) -> String {
+    let largest = (5_i64.pow(size.height.min(15) as u32) - 1) / 2;
+    (0..size.width)
+        .map(|_| to_snafu(rng.range(1..=largest)))
+        .join("\n")
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 25;
+    const SAMPLE: &'static str = include_str!("../bin/day25/sample.txt");
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, rng, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/day25/sample.txt");
+
+    #[test]
+    fn test_snafu_round_trip() {
+        rng::check(500, |rng| {
+            // pick the number of digits first, so that short numbers are
+            // tried as often as long ones
+            let digits = rng.range(0..=25) as u32;
+            let value = rng.range(-5i64.pow(digits)..=5i64.pow(digits));
+            assert_eq!(from_snafu(&to_snafu(value)), value, "{}", value);
+        });
+    }
+
+    #[test]
+    fn test_negative() {
+        assert_eq!(to_snafu(-3), "-2");
+        assert_eq!(p1(&Day25::parse("-").unwrap()), "-");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day25::parse("").err().unwrap();
+        assert_eq!(err.expected, "a SNAFU number");
+        assert_eq!(err.found, "end of input");
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Day25::parse(SAMPLE_INPUT).unwrap()), "2=-1=0");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Day25>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Day25::parse(SAMPLE_INPUT).unwrap()), "");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Day25>(Part::Two);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    parse::{self, Line, ParseError},
+    Answer, Solution,
+};
+
+fn parse_input(input: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    Ok(parse::lines(input).collect())
+}
+
+fn p1(_lines: &[Line]) -> Answer {
+    "".into()
+}
+
+fn p2(_lines: &[Line]) -> Answer {
+    "".into()
+}
+
+pub struct Empty;
+
+impl Solution for Empty {
+    const YEAR: u16 = 0;
+    const DAY: u8 = 0;
+    const SAMPLE: &'static str = include_str!("../bin/empty/sample.txt");
+
+    type Input<'a> = Vec<Line<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        p1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        p2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = include_str!("../bin/empty/sample.txt");
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(p1(&Empty::parse(SAMPLE_INPUT).unwrap()), "");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual::<Empty>(Part::One);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(p2(&Empty::parse(SAMPLE_INPUT).unwrap()), "");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual::<Empty>(Part::Two);
+    }
+}
@@ -0,0 +1,83 @@
+//! Random puzzle inputs, for stress testing the days on inputs larger or
+//! stranger than the actual ones.
+
+use std::{fmt::Display, str::FromStr};
+
+/// How big a generated input should be. Grid-like days use both
+/// dimensions, while days whose input is a list only use the `width`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Size {
+    pub const fn new(width: usize, height: usize) -> Self {
+        Self { width, height }
+    }
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    /// Reads `WxH`, or a single `N` as `NxN`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let dimension = |text: &str| {
+            text.parse::<usize>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("{} is not a valid size", value))
+        };
+
+        match value.split_once('x') {
+            Some((width, height)) => Ok(Self::new(dimension(width)?, dimension(height)?)),
+            None => dimension(value).map(|n| Self::new(n, n)),
+        }
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, Part};
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!("200x50".parse(), Ok(Size::new(200, 50)));
+        assert_eq!("7".parse(), Ok(Size::new(7, 7)));
+        assert!("0x5".parse::<Size>().is_err());
+        assert!("3x".parse::<Size>().is_err());
+        assert!("big".parse::<Size>().is_err());
+        assert_eq!(Size::new(3, 4).to_string(), "3x4");
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for solution in days::ALL {
+            for (seed, size) in [Size::new(1, 1), Size::new(5, 4), Size::new(12, 8)]
+                .into_iter()
+                .enumerate()
+            {
+                let input = solution
+                    .generate(seed as u64, size)
+                    .unwrap_or_else(|| panic!("day {} has no generator", solution.day()));
+                if let Err(err) = solution.solve(&input, &Part::BOTH) {
+                    panic!(
+                        "day {} rejected its {} input from seed {}: {}\n{}",
+                        solution.day(),
+                        size,
+                        seed,
+                        err,
+                        input
+                    );
+                }
+            }
+        }
+    }
+}
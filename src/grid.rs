@@ -0,0 +1,230 @@
+//! A dense, rectangular 2D grid addressed by `(x, y)`, with `(0, 0)` at the
+//! top left.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{self, ParseError},
+    point::{Direction, Point2},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid out of equally long rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a grid must be equally long"
+        );
+
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a character map, see [`parse::grid`].
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        parse::grid(input, expected, cell).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point2<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Point2<usize>) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<usize>) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Moves from `pos` by `delta`, if that stays inside the grid.
+    pub fn offset(&self, pos: Point2<usize>, delta: Point2<isize>) -> Option<Point2<usize>> {
+        pos.checked_add_signed(delta)
+            .filter(|next| self.contains(*next))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point2<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point2::new(x, y)))
+    }
+
+    /// Every position along with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours4(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.delta()))
+    }
+
+    pub fn neighbours8(&self, pos: Point2<usize>) -> impl Iterator<Item = Point2<usize>> + '_ {
+        Point2::around()
+            .into_iter()
+            .filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The positions met when walking from `pos` (exclusive) in steps of
+    /// `delta` until falling off the grid.
+    pub fn ray(
+        &self,
+        pos: Point2<usize>,
+        delta: Point2<isize>,
+    ) -> impl Iterator<Item = Point2<usize>> + '_ {
+        std::iter::successors(self.offset(pos, delta), move |pos| self.offset(*pos, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks_exact panics on 0, and an empty grid has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point2<usize>) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point2<usize>) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+/// Renders one character per cell, which is the same shape as the puzzle
+/// inputs.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u32> {
+        Grid::parse("123\n456\n", "a digit", |ch| ch.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_indexing() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 0)], 3);
+        assert_eq!(grid[Point2::new(0, 1)], 4);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(Point2::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours8(Point2::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = sample();
+        assert_eq!(
+            grid.ray(Point2::new(0, 0), Direction::Right.delta())
+                .collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(2, 0)]
+        );
+        assert_eq!(
+            grid.ray(Point2::new(0, 0), Direction::Left.delta()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = sample();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = sample().map(|digit| if digit % 2 == 0 { '#' } else { '.' });
+        assert_eq!(grid.to_string(), ".#.\n#.#");
+
+        grid[Point2::new(1, 1)] = '#';
+        assert_eq!(grid.to_string(), ".#.\n###");
+    }
+}
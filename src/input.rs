@@ -0,0 +1,137 @@
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable that overrides where the actual inputs are looked up.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "{} not found (check out the actual_inputs submodule, or point {} or --input-dir at your inputs)",
+                path.display(),
+                INPUT_DIR_VAR
+            ),
+            InputError::Unreadable(path, err) => {
+                write!(f, "cannot read {}: {}", path.display(), err)
+            }
+            InputError::Invalid(path, reason) => write!(f, "{}: {}", path.display(), reason),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Locates the personal puzzle inputs, laid out as
+/// `<root>/<year>/<day>/input.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputDir {
+    root: PathBuf,
+}
+
+impl InputDir {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Uses [`INPUT_DIR_VAR`] if set, otherwise [`InputDir::checkout`].
+    pub fn from_env() -> Self {
+        match std::env::var_os(INPUT_DIR_VAR) {
+            Some(root) => Self::new(root),
+            None => Self::checkout(),
+        }
+    }
+
+    /// The `actual_inputs` checkout next to `Cargo.toml`.
+    pub fn checkout() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("actual_inputs"))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("{:02}", day))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input.txt")
+    }
+
+    /// Expected answers live next to the input, see [`crate::answers`].
+    pub fn answers_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("answers.txt")
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read(self.input_path(year, day))
+    }
+}
+
+pub(crate) fn read(path: PathBuf) -> Result<String, InputError> {
+    std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path),
+        _ => InputError::Unreadable(path, err),
+    })
+}
+
+/// Loads the actual input for a day in tests. When the
+/// input is not checked out, the test is reported as skipped and `None` is
+/// returned, so that `cargo test` still passes on the samples alone.
+#[cfg(test)]
+pub(crate) fn actual_input(year: u16, day: u8) -> Option<String> {
+    match InputDir::from_env().load(year, day) {
+        Ok(input) => Some(input),
+        Err(err @ InputError::Missing(..)) => {
+            report_skipped(err);
+            None
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn report_skipped(reason: impl Display) {
+    use std::io::Write;
+
+    // written to the raw handle, as the test harness swallows eprintln!
+    // output of passing tests
+    let thread = std::thread::current();
+    let _ = writeln!(
+        std::io::stderr(),
+        "skipped {}: {}",
+        thread.name().unwrap_or("test"),
+        reason
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            InputDir::new("inputs").input_path(2022, 7),
+            Path::new("inputs/2022/07/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let dir = InputDir::new("does/not/exist");
+        assert!(matches!(dir.load(2022, 1), Err(InputError::Missing(..))));
+    }
+}
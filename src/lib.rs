@@ -0,0 +1,168 @@
+use std::{fmt::Display, process::ExitCode};
+
+pub use answer::Answer;
+use bench::{Run, Timings};
+use generate::Size;
+use input::InputDir;
+use parse::ParseError;
+use rng::Rng;
+use visualize::Recorder;
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod point;
+pub mod rng;
+pub mod scaffold;
+pub mod search;
+pub mod visualize;
+pub mod years;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle. The raw input is parsed once, and both parts
+/// borrow the parsed result. Malformed input is rejected while parsing, so
+/// the parts themselves never fail; input that parses but has no answer for
+/// a part gets an [`Answer::Unsolved`] for it.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const SAMPLE: &'static str;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Answer;
+    fn part2(input: &Self::Input<'_>) -> Answer;
+
+    /// Replays the simulation behind the puzzle into `recorder`, one frame
+    /// per step. Returns `false` for days that have nothing to show.
+    fn visualize(_input: &Self::Input<'_>, _recorder: &mut Recorder) -> bool {
+        false
+    }
+
+    /// Writes a random input of roughly the given `size` that [`parse`]
+    /// accepts and both parts can solve. Returns `None` for days without a
+    /// generator.
+    ///
+    /// [`parse`]: Solution::parse
+    fn generate(_rng: &mut Rng, _size: Size) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input
+/// types can live side by side in the registry.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn sample(&self) -> &'static str;
+
+    /// Parses `input` once and returns the answers to `parts`, in order.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError>;
+
+    /// See [`bench::run_once`].
+    fn run_once(&self, input: &str) -> Result<Run, ParseError>;
+
+    /// See [`bench::bench`].
+    fn bench(&self, input: &str, iterations: usize) -> Result<Timings, ParseError>;
+
+    /// See [`Solution::visualize`].
+    fn visualize(&self, input: &str, recorder: &mut Recorder) -> Result<bool, ParseError>;
+
+    /// See [`Solution::generate`]. The same seed always gives the same input.
+    fn generate(&self, seed: u64, size: Size) -> Option<String>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn sample(&self) -> &'static str {
+        S::SAMPLE
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+        let input = S::parse(input)?;
+        Ok(parts
+            .iter()
+            .map(|part| match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            })
+            .collect())
+    }
+
+    fn run_once(&self, input: &str) -> Result<Run, ParseError> {
+        bench::run_once::<S>(input)
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<Timings, ParseError> {
+        bench::bench::<S>(input, iterations)
+    }
+
+    fn visualize(&self, input: &str, recorder: &mut Recorder) -> Result<bool, ParseError> {
+        Ok(S::visualize(&S::parse(input)?, recorder))
+    }
+
+    fn generate(&self, seed: u64, size: Size) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+}
+
+/// Entry point shared by the per-day binaries: solves both parts against the
+/// actual input.
+pub fn run<S: Solution>() -> ExitCode {
+    let input = match InputDir::from_env().load(S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: invalid input: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", S::part1(&input));
+    println!("{}", S::part2(&input));
+    ExitCode::SUCCESS
+}
@@ -1,3 +1,1241 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    io::Read,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2022_rust::{
+    answers::{self, Answers, Verdict},
+    bench::Run,
+    generate::Size,
+    input::{InputDir, InputError},
+    parallel,
+    parse::ParseError,
+    scaffold,
+    visualize::{Output, Recorder},
+    years, Answer, DynSolution, Part,
+};
+use itertools::{EitherOrBoth, Itertools};
+
+const USAGE: &str = "\
+Usage: aoc [run] [--year <YYYY>] (--day <N|FROM-TO> | --all) [--part <1|2>]
+                 [--input <PATH|-> | --sample | --input-dir <DIR>]
+       aoc verify [--year <YYYY>] [--day <N|FROM-TO>] [--input-dir <DIR>]
+                 [--record]
+       aoc bench [--year <YYYY>] [--day <N|FROM-TO>] [--iterations <N>]
+                 [--sample | --input-dir <DIR>] [--json <PATH|->]
+       aoc new [--year <YYYY>] --day <N>
+       aoc visualize [--year <YYYY>] --day <N> [--to <terminal|text|ppm>] [--out <DIR>]
+                 [--frames <N>] [--delay <MS>] [--scale <N>]
+                 [--input <PATH|-> | --sample | --input-dir <DIR>]
+       aoc gen [--year <YYYY>] --day <N> [--size <WxH|N>] [--seed <N>]
+       aoc parallel [--year <YYYY>] [--day <N|FROM-TO>] [--jobs <N>]
+                 [--sample | --input-dir <DIR>]
+
+Commands:
+  run     solve the selected days and print the answers (default)
+  verify  check the selected days (default: all) against answers.txt
+  bench   time parsing and each part of the selected days (default: all)
+  new     create and register a new day from the `empty` template, along
+          with the year's module the first time one of its days is added
+  visualize  replay a simulation day (9, 14, 17, 23, 24) frame by frame
+  gen     print a random input for a day, for stress testing
+  parallel  solve the selected days (default: all) on worker threads, and
+            sum up their answers, timings and panics in one table
+
+Options:
+  -y, --year <YYYY>      the year whose days to select (default: the latest
+                         year with implemented days)
+  -d, --day <N|FROM-TO>  select a single day, or an inclusive range of days
+  -a, --all              select every implemented day of the year
+  -p, --part <1|2>       only run the given part (default: both)
+  -i, --input <PATH|->   read the puzzle input from PATH, or stdin for `-`
+  -s, --sample           use the bundled sample.txt instead of the actual input
+      --input-dir <DIR>  look up actual inputs under DIR/<year>/<day>/input.txt
+                         (default: $AOC_INPUT_DIR, or actual_inputs/)
+      --record           have verify first write the current answers of the
+                         parts that answers.txt has none for into it
+  -n, --iterations <N>   number of runs to time in bench mode (default: 10)
+      --json <PATH|->    also write the bench report as JSON to PATH, or only
+                         to stdout for `-`
+      --to <FORMAT>      where visualize sends its frames: an animation in the
+                         terminal (default), or numbered text or PPM files
+      --out <DIR>        directory for text and PPM frames (default: frames/)
+      --frames <N>       stop visualizing after N frames
+      --delay <MS>       pause between terminal frames (default: 50)
+      --scale <N>        pixels per tile in PPM frames (default: 4)
+      --size <WxH|N>     dimensions of a generated input, whose meaning
+                         depends on the day (default: 10)
+      --seed <N>         seed of a generated input (default: 0)
+  -j, --jobs <N>         worker threads for parallel (default: one per CPU)
+  -h, --help             print this help";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Selection {
+    All,
+    Days(RangeInclusive<u8>),
+}
+
+impl Selection {
+    fn is_single_day(&self) -> bool {
+        matches!(self, Selection::Days(days) if days.start() == days.end())
+    }
+
+    fn solutions(&self, year: u16) -> Result<Vec<&'static dyn DynSolution>, String> {
+        let days =
+            years::days(year).ok_or_else(|| format!("no days of {} are implemented", year))?;
+        match self {
+            Selection::All => Ok(days.to_vec()),
+            Selection::Days(range) if self.is_single_day() => {
+                Ok(vec![years::find(year, *range.start()).ok_or_else(
+                    || format!("day {} of {} is not implemented", range.start(), year),
+                )?])
+            }
+            Selection::Days(range) => Ok(days
+                .iter()
+                .copied()
+                .filter(|solution| range.contains(&solution.day()))
+                .collect()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InputSource {
+    Actual(InputDir),
+    Sample,
+    Stdin,
+    File(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunOptions {
+    year: u16,
+    selection: Selection,
+    parts: Vec<Part>,
+    input: InputSource,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct VerifyOptions {
+    year: u16,
+    selection: Selection,
+    input_dir: InputDir,
+    /// Seed answers.txt with the answers it has none for before checking.
+    record: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct BenchOptions {
+    year: u16,
+    selection: Selection,
+    input: InputSource,
+    iterations: usize,
+    json: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct VisualizeOptions {
+    year: u16,
+    day: u8,
+    input: InputSource,
+    output: Output,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ParallelOptions {
+    year: u16,
+    selection: Selection,
+    input: InputSource,
+    jobs: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Bench(BenchOptions),
+    New {
+        year: u16,
+        day: u8,
+    },
+    Visualize(VisualizeOptions),
+    Gen {
+        year: u16,
+        day: u8,
+        size: Size,
+        seed: u64,
+    },
+    Parallel(ParallelOptions),
+    Help,
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    // the first Advent of Code was in 2015
+    value
+        .trim()
+        .parse::<u16>()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| format!("{} is not a valid year", value))
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("{} is not a valid day", day))
+    };
+
+    match value.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("{} is an empty range of days", value));
+            }
+            Ok(from..=to)
+        }
+        None => parse_day(value).map(|day| day..=day),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("{} is not a valid part", value)),
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("verify") => parse_verify(args.skip(1)),
+        Some("bench") => parse_bench(args.skip(1)),
+        Some("new") => parse_new(args.skip(1)),
+        Some("visualize") => parse_visualize(args.skip(1)),
+        Some("gen") => parse_gen(args.skip(1)),
+        Some("parallel") => parse_parallel(args.skip(1)),
+        Some("run") => parse_run(args.skip(1)),
+        _ => parse_run(args),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut selection = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = InputSource::Actual(InputDir::from_env());
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => selection = Some(Selection::Days(parse_days(&value()?)?)),
+            "-a" | "--all" => selection = Some(Selection::All),
+            "-p" | "--part" => parts = vec![parse_part(&value()?)?],
+            "-i" | "--input" => {
+                input = match value()?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(path.to_string()),
+                }
+            }
+            "-s" | "--sample" => input = InputSource::Sample,
+            "--input-dir" => input = InputSource::Actual(InputDir::new(value()?)),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    let selection = selection.ok_or("either --day or --all is required")?;
+
+    if !selection.is_single_day() && matches!(input, InputSource::Stdin | InputSource::File(..)) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    Ok(Command::Run(RunOptions {
+        year,
+        selection,
+        parts,
+        input,
+    }))
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut selection = Selection::All;
+    let mut input_dir = InputDir::from_env();
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => selection = Selection::Days(parse_days(&value()?)?),
+            "-a" | "--all" => selection = Selection::All,
+            "--input-dir" => input_dir = InputDir::new(value()?),
+            "--record" => record = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Command::Verify(VerifyOptions {
+        year,
+        selection,
+        input_dir,
+        record,
+    }))
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut selection = Selection::All;
+    let mut input = InputSource::Actual(InputDir::from_env());
+    let mut iterations = 10;
+    let mut json = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => selection = Selection::Days(parse_days(&value()?)?),
+            "-a" | "--all" => selection = Selection::All,
+            "-s" | "--sample" => input = InputSource::Sample,
+            "--input-dir" => input = InputSource::Actual(InputDir::new(value()?)),
+            "-n" | "--iterations" => {
+                let count = value()?;
+                iterations = count
+                    .parse()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or_else(|| format!("{} is not a valid number of iterations", count))?;
+            }
+            "--json" => json = Some(value()?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Command::Bench(BenchOptions {
+        year,
+        selection,
+        input,
+        iterations,
+        json,
+    }))
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => {
+                let days = parse_days(&value()?)?;
+                if days.start() != days.end() {
+                    return Err("new only creates a single day at a time".to_string());
+                }
+                day = Some(*days.start());
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Command::New {
+        year,
+        day: day.ok_or("--day is required")?,
+    })
+}
+
+fn parse_visualize(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut day = None;
+    let mut input = InputSource::Actual(InputDir::from_env());
+    let mut format = "terminal".to_string();
+    let mut dir = PathBuf::from("frames");
+    let mut limit = None;
+    let mut delay = 50;
+    let mut scale = 4;
+
+    fn parse_count(value: &str, what: &str) -> Result<usize, String> {
+        value
+            .parse()
+            .ok()
+            .filter(|count| *count > 0)
+            .ok_or_else(|| format!("{} is not a valid {}", value, what))
+    }
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => {
+                let days = parse_days(&value()?)?;
+                if days.start() != days.end() {
+                    return Err("visualize only shows a single day at a time".to_string());
+                }
+                day = Some(*days.start());
+            }
+            "-i" | "--input" => {
+                input = match value()?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(path.to_string()),
+                }
+            }
+            "-s" | "--sample" => input = InputSource::Sample,
+            "--input-dir" => input = InputSource::Actual(InputDir::new(value()?)),
+            "--to" => format = value()?,
+            "--out" => dir = PathBuf::from(value()?),
+            "--frames" => limit = Some(parse_count(&value()?, "number of frames")?),
+            "--delay" => {
+                let ms = value()?;
+                delay = ms
+                    .parse()
+                    .map_err(|_| format!("{} is not a valid delay", ms))?;
+            }
+            "--scale" => scale = parse_count(&value()?, "scale")?,
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    let output = match format.as_str() {
+        "terminal" => Output::Terminal {
+            delay: Duration::from_millis(delay),
+        },
+        "text" => Output::Text(dir),
+        "ppm" => Output::Ppm { dir, scale },
+        _ => return Err(format!("{} is not a valid frame format", format)),
+    };
+
+    Ok(Command::Visualize(VisualizeOptions {
+        year,
+        day: day.ok_or("--day is required")?,
+        input,
+        output,
+        limit,
+    }))
+}
+
+fn parse_gen(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut day = None;
+    let mut size = Size::new(10, 10);
+    let mut seed = 0;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => {
+                let days = parse_days(&value()?)?;
+                if days.start() != days.end() {
+                    return Err("gen only generates a single day at a time".to_string());
+                }
+                day = Some(*days.start());
+            }
+            "--size" => size = value()?.parse()?,
+            "--seed" => {
+                let value = value()?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("{} is not a valid seed", value))?;
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Command::Gen {
+        year,
+        day: day.ok_or("--day is required")?,
+        size,
+        seed,
+    })
+}
+
+fn parse_parallel(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = years::latest();
+    let mut selection = Selection::All;
+    let mut input = InputSource::Actual(InputDir::from_env());
+    let mut jobs = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} expects a value", arg))
+        };
+
+        match arg.as_str() {
+            "-y" | "--year" => year = parse_year(&value()?)?,
+            "-d" | "--day" => selection = Selection::Days(parse_days(&value()?)?),
+            "-a" | "--all" => selection = Selection::All,
+            "-s" | "--sample" => input = InputSource::Sample,
+            "--input-dir" => input = InputSource::Actual(InputDir::new(value()?)),
+            "-j" | "--jobs" => {
+                let count = value()?;
+                jobs = Some(
+                    count
+                        .parse()
+                        .ok()
+                        .filter(|count| *count > 0)
+                        .ok_or_else(|| format!("{} is not a valid number of jobs", count))?,
+                );
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+
+    Ok(Command::Parallel(ParallelOptions {
+        year,
+        selection,
+        input,
+        jobs,
+    }))
+}
+
+fn read_input(solution: &dyn DynSolution, source: &InputSource) -> Result<String, String> {
+    match source {
+        InputSource::Actual(dir) => dir
+            .load(solution.year(), solution.day())
+            .map_err(|err| format!("day {}: {}", solution.day(), err)),
+        InputSource::Sample => Ok(solution.sample().to_string()),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("cannot read stdin: {}", err))?;
+            Ok(input)
+        }
+        InputSource::File(path) => {
+            std::fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path, err))
+        }
+    }
+}
+
+fn run(options: &RunOptions) -> Result<bool, String> {
+    let solutions = options.selection.solutions(options.year)?;
+
+    // a lone answer is printed bare, so that it can be piped elsewhere
+    let bare = solutions.len() == 1 && options.parts.len() == 1;
+    let mut solved = true;
+
+    for solution in solutions {
+        let input = read_input(solution, &options.input)?;
+        let answers = solution
+            .solve(&input, &options.parts)
+            .map_err(|err| format!("day {}: invalid input: {}", solution.day(), err))?;
+
+        for (part, answer) in options.parts.iter().zip(answers) {
+            if let Some(reason) = answer.unsolved() {
+                eprintln!(
+                    "error: day {} part {}: no answer: {}",
+                    solution.day(),
+                    part,
+                    reason
+                );
+                solved = false;
+            } else if bare {
+                println!("{}", answer);
+            } else if answer.is_multiline() {
+                println!("day{:02} p{}:\n{}", solution.day(), part.number(), answer);
+            } else {
+                println!("day{:02} p{}: {}", solution.day(), part.number(), answer);
+            }
+            if let Some(err) = answer.ocr_error() {
+                eprintln!(
+                    "warning: day {} part {}: cannot read the letters: {}",
+                    solution.day(),
+                    part,
+                    err
+                );
+            }
+        }
+    }
+
+    Ok(solved)
+}
+
+/// Prints single-line answers one after the other, and multi-line answers
+/// side by side with differing rows marked.
+fn print_diff(expected: &str, actual: &Answer) {
+    if !actual.is_multiline() && !expected.trim().contains('\n') {
+        println!("  expected {}\n  actual   {}", expected.trim(), actual);
+        return;
+    }
+
+    // letters are compared to a hand-copied picture by their own picture
+    let actual = format!("{:#}", actual);
+    let expected = expected.trim_matches('\n').lines().collect::<Vec<_>>();
+    let width = expected.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    println!("  {:width$}   actual", "expected", width = width);
+    for rows in expected.iter().zip_longest(actual.lines()) {
+        let (expected, actual) = match rows {
+            EitherOrBoth::Both(expected, actual) => (*expected, actual),
+            EitherOrBoth::Left(expected) => (*expected, ""),
+            EitherOrBoth::Right(actual) => ("", actual),
+        };
+        let marker = if expected.trim_end() == actual.trim_end() {
+            ' '
+        } else {
+            '!'
+        };
+        println!("{} {:width$} | {}", marker, expected, actual, width = width);
+    }
+}
+
+fn verify(options: &VerifyOptions) -> Result<bool, String> {
+    let mut failures = vec![];
+    let (mut passed, mut recorded, mut missing, mut invalid) = (0, 0, 0, 0);
+
+    println!("day  part  result");
+    for solution in options.selection.solutions(options.year)? {
+        let new = if options.record {
+            answers::record(&options.input_dir, solution).unwrap_or_else(|err| {
+                eprintln!(
+                    "warning: day {}: cannot record answers: {}",
+                    solution.day(),
+                    err
+                );
+                vec![]
+            })
+        } else {
+            vec![]
+        };
+
+        for (part, verdict) in answers::verify(&options.input_dir, solution) {
+            let result = match verdict {
+                Verdict::Pass if new.contains(&part) => {
+                    recorded += 1;
+                    "recorded".to_string()
+                }
+                Verdict::Pass => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                Verdict::Fail { actual, expected } => {
+                    failures.push((solution.day(), part, actual, expected));
+                    "FAIL".to_string()
+                }
+                Verdict::Missing(reason) => {
+                    missing += 1;
+                    format!("missing ({})", reason)
+                }
+                Verdict::Invalid(err) => {
+                    invalid += 1;
+                    format!("INVALID ({})", err)
+                }
+                Verdict::Unreadable(reason) => {
+                    invalid += 1;
+                    format!("INVALID ({})", reason)
+                }
+            };
+            println!("{:>3}  {:>4}  {}", solution.day(), part.number(), result);
+        }
+    }
+
+    for (day, part, actual, expected) in &failures {
+        println!("\nday {} part {}:", day, part);
+        print_diff(expected, actual);
+        if let Some(err) = actual.ocr_error() {
+            println!("  cannot read the letters: {}", err);
+        }
+    }
+    print!(
+        "\n{} passed, {} failed, {} invalid, {} missing",
+        passed,
+        failures.len(),
+        invalid,
+        missing
+    );
+    if options.record {
+        print!(", {} recorded", recorded);
+    }
+    println!();
+
+    Ok(failures.is_empty() && invalid == 0)
+}
+
+fn bench(options: &BenchOptions) -> Result<bool, String> {
+    let json_only = options.json.as_deref() == Some("-");
+    let mut reports = vec![];
+
+    if !json_only {
+        println!("day  step   {:>10}  {:>10}  {:>10}", "min", "median", "max");
+    }
+    for solution in options.selection.solutions(options.year)? {
+        let input = read_input(solution, &options.input)?;
+        let timings = solution
+            .bench(&input, options.iterations)
+            .map_err(|err| format!("day {}: invalid input: {}", solution.day(), err))?;
+
+        if !json_only {
+            for (step, stats) in timings.steps() {
+                println!(
+                    "{:>3}  {}  {:>10}  {:>10}  {:>10}",
+                    timings.day,
+                    step,
+                    format!("{:.1?}", stats.min),
+                    format!("{:.1?}", stats.median),
+                    format!("{:.1?}", stats.max)
+                );
+            }
+        }
+        reports.push(timings.to_json());
+    }
+
+    if let Some(target) = &options.json {
+        let report = serde_json::to_string_pretty(&serde_json::json!({
+            "year": options.year,
+            "iterations": options.iterations,
+            "days": reports,
+        }))
+        .map_err(|err| err.to_string())?;
+
+        if json_only {
+            println!("{}", report);
+        } else {
+            std::fs::write(target, report + "\n")
+                .map_err(|err| format!("cannot write {}: {}", target, err))?;
+        }
+    }
+
+    Ok(true)
+}
+
+fn new(year: u16, day: u8) -> Result<bool, String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold::new_day(root, year, day)? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    Ok(true)
+}
+
+fn find(year: u16, day: u8) -> Result<&'static dyn DynSolution, String> {
+    years::find(year, day).ok_or_else(|| format!("day {} of {} is not implemented", day, year))
+}
+
+fn visualize(options: VisualizeOptions) -> Result<bool, String> {
+    let solution = find(options.year, options.day)?;
+    let input = read_input(solution, &options.input)?;
+
+    let destination = match &options.output {
+        Output::Terminal { .. } => None,
+        Output::Text(dir) | Output::Ppm { dir, .. } => Some(dir.clone()),
+    };
+    let mut recorder = Recorder::new(options.output, options.limit);
+
+    let supported = solution
+        .visualize(&input, &mut recorder)
+        .map_err(|err| format!("day {}: invalid input: {}", solution.day(), err))?;
+    if !supported {
+        return Err(format!("day {} has nothing to visualize", solution.day()));
+    }
+
+    let frames = recorder
+        .finish()
+        .map_err(|err| format!("cannot write frames: {}", err))?;
+    if let Some(dir) = destination {
+        println!("wrote {} frames to {}", frames, dir.display());
+    }
+    Ok(true)
+}
+
+fn gen(year: u16, day: u8, size: Size, seed: u64) -> Result<bool, String> {
+    let solution = find(year, day)?;
+    let input = solution
+        .generate(seed, size)
+        .ok_or_else(|| format!("day {} has no input generator", day))?;
+    println!("{}", input);
+    Ok(true)
+}
+
+/// How a day fared in a parallel run, short of panicking.
+enum Outcome {
+    Solved(Box<Run>, [Verdict; 2]),
+    /// Solved, but the expected answers could not be read to check it.
+    BadAnswers(Box<Run>, InputError),
+    Missing(String),
+    Invalid(ParseError),
+}
+
+fn parallel(options: &ParallelOptions) -> Result<bool, String> {
+    let solutions = options.selection.solutions(options.year)?;
+    let jobs = options
+        .jobs
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |count| count.get()));
+
+    let start = Instant::now();
+    let outcomes = parallel::for_each_day(&solutions, jobs, |solution| {
+        // the table shows the day already, so the reason leaves it out
+        let input = match &options.input {
+            InputSource::Actual(dir) => {
+                dir.load(solution.year(), solution.day())
+                    .map_err(|err| match err {
+                        InputError::Missing(path) => format!("no {}", path.display()),
+                        err => err.to_string(),
+                    })
+            }
+            source => read_input(solution, source),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => return Outcome::Missing(err),
+        };
+        let run = match solution.run_once(&input) {
+            Ok(run) => run,
+            Err(err) => return Outcome::Invalid(err),
+        };
+        // samples have no answers store, so their answers go unchecked, as
+        // do those of a day without an answers file
+        let answers = match &options.input {
+            InputSource::Actual(dir) => match Answers::load(dir, solution.year(), solution.day()) {
+                Ok(answers) => answers,
+                Err(InputError::Missing(..)) => Answers::default(),
+                Err(err) => return Outcome::BadAnswers(Box::new(run), err),
+            },
+            _ => Answers::default(),
+        };
+        let verdicts = [
+            answers.verdict(Part::One, run.part1.0.clone()),
+            answers.verdict(Part::Two, run.part2.0.clone()),
+        ];
+        Outcome::Solved(Box::new(run), verdicts)
+    });
+    let elapsed = start.elapsed();
+
+    let cell = |answer: &Answer| {
+        if answer.is_multiline() {
+            format!("<{} rows>", answer.to_string().lines().count())
+        } else {
+            answer.to_string()
+        }
+    };
+    let solved = |day: String, run: &Run, result: String| {
+        [
+            day,
+            format!("{:.1?}", run.parse),
+            cell(&run.part1.0),
+            format!("{:.1?}", run.part1.1),
+            cell(&run.part2.0),
+            format!("{:.1?}", run.part2.1),
+            result,
+        ]
+    };
+    let (mut passed, mut failed, mut panicked, mut invalid, mut missing, mut unchecked) =
+        (0, 0, 0, 0, 0, 0);
+    let mut details = vec![];
+    let mut rows =
+        vec![["day", "parse", "part 1", "time", "part 2", "time", "result"].map(String::from)];
+
+    for (solution, outcome) in solutions.iter().zip(&outcomes) {
+        let day = solution.day().to_string();
+        let blank = String::new;
+        rows.push(match outcome {
+            Ok(Outcome::Solved(run, verdicts)) => {
+                let failures = Part::BOTH
+                    .iter()
+                    .zip(verdicts)
+                    .filter(|(_, verdict)| matches!(verdict, Verdict::Fail { .. }))
+                    .collect::<Vec<_>>();
+                let passes = verdicts
+                    .iter()
+                    .filter(|verdict| **verdict == Verdict::Pass)
+                    .count();
+
+                let result = if !failures.is_empty() {
+                    failed += 1;
+                    format!(
+                        "FAIL (part {})",
+                        failures.iter().map(|(part, _)| part).join(", ")
+                    )
+                } else if passes == 0 {
+                    unchecked += 1;
+                    "unchecked".to_string()
+                } else {
+                    passed += 1;
+                    "pass".to_string()
+                };
+                details.extend(failures.into_iter().map(|(part, verdict)| {
+                    (solution.day(), format!("part {}", part), Some(verdict))
+                }));
+
+                solved(day, run, result)
+            }
+            Ok(Outcome::BadAnswers(run, err)) => {
+                failed += 1;
+                details.push((solution.day(), format!("answers: {}", err), None));
+                solved(day, run, "FAIL (bad answers file)".to_string())
+            }
+            Ok(Outcome::Missing(reason)) => {
+                missing += 1;
+                [
+                    day,
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    format!("missing ({})", reason),
+                ]
+            }
+            Ok(Outcome::Invalid(err)) => {
+                invalid += 1;
+                [
+                    day,
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    format!("INVALID ({})", err),
+                ]
+            }
+            Err(message) => {
+                panicked += 1;
+                details.push((solution.day(), format!("panicked: {}", message), None));
+                let first_line = message.lines().next().unwrap_or_default();
+                [
+                    day,
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    blank(),
+                    format!("PANIC ({})", first_line),
+                ]
+            }
+        });
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap())
+        .collect::<Vec<_>>();
+    for row in &rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (text, width))| match column {
+                0 => format!("{:>width$}", text, width = width),
+                _ => format!("{:width$}", text, width = width),
+            })
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    for (day, what, verdict) in details {
+        println!("\nday {} {}", day, what);
+        if let Some(Verdict::Fail { actual, expected }) = verdict {
+            print_diff(expected, actual);
+            if let Some(err) = actual.ocr_error() {
+                println!("  cannot read the letters: {}", err);
+            }
+        }
+    }
+    println!(
+        "\n{} days in {:.1?}: {} passed, {} failed, {} panicked, {} invalid, {} missing, {} unchecked",
+        rows.len() - 1,
+        elapsed,
+        passed,
+        failed,
+        panicked,
+        invalid,
+        missing,
+        unchecked
+    );
+
+    Ok(failed == 0 && panicked == 0 && invalid == 0)
+}
+
+fn main() -> ExitCode {
+    let result = match parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::Verify(options)) => verify(&options),
+        Ok(Command::Bench(options)) => bench(&options),
+        Ok(Command::New { year, day }) => new(year, day),
+        Ok(Command::Visualize(options)) => visualize(options),
+        Ok(Command::Gen {
+            year,
+            day,
+            size,
+            seed,
+        }) => gen(year, day, size, seed),
+        Ok(Command::Parallel(options)) => parallel(&options),
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("12"), Ok(12..=12));
+        assert_eq!(parse_days("3-7"), Ok(3..=7));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("7-3").is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args("--day 12 --part 2")),
+            Ok(Command::Run(RunOptions {
+                year: years::latest(),
+                selection: Selection::Days(12..=12),
+                parts: vec![Part::Two],
+                input: InputSource::Actual(InputDir::from_env()),
+            }))
+        );
+        assert_eq!(
+            parse_args(args("--all -s")),
+            Ok(Command::Run(RunOptions {
+                year: years::latest(),
+                selection: Selection::All,
+                parts: vec![Part::One, Part::Two],
+                input: InputSource::Sample,
+            }))
+        );
+        assert!(matches!(
+            parse_args(args("run -d 5 -i -")),
+            Ok(Command::Run(RunOptions {
+                input: InputSource::Stdin,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            parse_args(args("-d 5 --input-dir elsewhere")),
+            Ok(Command::Run(RunOptions {
+                input: InputSource::Actual(dir),
+                ..
+            })) if dir == InputDir::new("elsewhere")
+        ));
+        assert_eq!(parse_args(args("--help")), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        assert_eq!(
+            parse_args(args("verify")),
+            Ok(Command::Verify(VerifyOptions {
+                year: years::latest(),
+                selection: Selection::All,
+                input_dir: InputDir::from_env(),
+                record: false,
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "verify --year 2023 --day 3-7 --input-dir elsewhere --record"
+            )),
+            Ok(Command::Verify(VerifyOptions {
+                year: 2023,
+                selection: Selection::Days(3..=7),
+                input_dir: InputDir::new("elsewhere"),
+                record: true,
+            }))
+        );
+        assert!(parse_args(args("verify --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            parse_args(args("bench -d 6 -n 100 --sample --json -")),
+            Ok(Command::Bench(BenchOptions {
+                year: years::latest(),
+                selection: Selection::Days(6..=6),
+                input: InputSource::Sample,
+                iterations: 100,
+                json: Some("-".to_string()),
+            }))
+        );
+        assert!(parse_args(args("bench -n 0")).is_err());
+        assert!(parse_args(args("bench --input -")).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_args() {
+        assert_eq!(
+            parse_args(args("new -d 15")),
+            Ok(Command::New {
+                year: years::latest(),
+                day: 15
+            })
+        );
+        assert_eq!(
+            parse_args(args("new -d 1 -y 2023")),
+            Ok(Command::New { year: 2023, day: 1 })
+        );
+        assert!(parse_args(args("new")).is_err());
+        assert!(parse_args(args("new -d 15-17")).is_err());
+        assert_eq!(
+            parse_args(args("new -d 0")),
+            Err("0 is not a valid day".to_string())
+        );
+        assert_eq!(
+            parse_args(args("new -d 26")),
+            Err("26 is not a valid day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_visualize_args() {
+        assert_eq!(
+            parse_args(args("visualize -d 14 --sample")),
+            Ok(Command::Visualize(VisualizeOptions {
+                year: years::latest(),
+                day: 14,
+                input: InputSource::Sample,
+                output: Output::Terminal {
+                    delay: Duration::from_millis(50),
+                },
+                limit: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args(
+                "visualize -d 23 --to ppm --out elsewhere --scale 2 --frames 10"
+            )),
+            Ok(Command::Visualize(VisualizeOptions {
+                year: years::latest(),
+                day: 23,
+                input: InputSource::Actual(InputDir::from_env()),
+                output: Output::Ppm {
+                    dir: PathBuf::from("elsewhere"),
+                    scale: 2,
+                },
+                limit: Some(10),
+            }))
+        );
+        assert!(parse_args(args("visualize")).is_err());
+        assert!(parse_args(args("visualize -d 9 --to gif")).is_err());
+        assert!(parse_args(args("visualize -d 9-10")).is_err());
+        assert!(parse_args(args("visualize -d 9 --frames 0")).is_err());
+    }
+
+    #[test]
+    fn test_parse_gen_args() {
+        assert_eq!(
+            parse_args(args("gen -y 2022 --day 24 --size 200x50 --seed 7")),
+            Ok(Command::Gen {
+                year: 2022,
+                day: 24,
+                size: Size::new(200, 50),
+                seed: 7,
+            })
+        );
+        assert_eq!(
+            parse_args(args("gen -d 11")),
+            Ok(Command::Gen {
+                year: years::latest(),
+                day: 11,
+                size: Size::new(10, 10),
+                seed: 0,
+            })
+        );
+        assert!(parse_args(args("gen")).is_err());
+        assert!(parse_args(args("gen -d 3 --size 0")).is_err());
+        assert!(parse_args(args("gen -d 3 --seed -1")).is_err());
+    }
+
+    #[test]
+    fn test_parse_parallel_args() {
+        assert_eq!(
+            parse_args(args("parallel")),
+            Ok(Command::Parallel(ParallelOptions {
+                year: years::latest(),
+                selection: Selection::All,
+                input: InputSource::Actual(InputDir::from_env()),
+                jobs: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("parallel -d 1-10 --jobs 3 --sample")),
+            Ok(Command::Parallel(ParallelOptions {
+                year: years::latest(),
+                selection: Selection::Days(1..=10),
+                input: InputSource::Sample,
+                jobs: Some(3),
+            }))
+        );
+        assert!(parse_args(args("parallel -j 0")).is_err());
+        assert!(parse_args(args("parallel --input -")).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("--day")).is_err());
+        assert!(parse_args(args("--day 1 --part 3")).is_err());
+        assert!(parse_args(args("--all --input input.txt")).is_err());
+        assert!(parse_args(args("--day 1-3 --input -")).is_err());
+        assert!(parse_args(args("--bogus")).is_err());
+        assert!(parse_args(args("--year 2014 --all")).is_err());
+        assert!(parse_args(args("--year next --all")).is_err());
+        assert!(parse_args(args("verify --year")).is_err());
+    }
 }
@@ -0,0 +1,143 @@
+//! Reads the capital letters drawn by puzzles such as day10, in the 4x6
+//! font that every such puzzle uses. Letters are 4 pixels wide with a blank
+//! column after each one.
+
+use std::fmt::Display;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// The letters seen in puzzle outputs so far, apart from I and Y. Those
+/// are not 4 pixels wide, so the font has no glyphs for them.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The picture is not a single row of letters.
+    Height(usize),
+    /// The glyph at `index`, counting letters from 0, is not in the font.
+    UnknownGlyph { index: usize, glyph: Vec<String> },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(
+                f,
+                "expected a picture {} rows high, found {} rows",
+                GLYPH_HEIGHT, height
+            ),
+            OcrError::UnknownGlyph { index, glyph } => write!(
+                f,
+                "letter {} is not in the font:\n{}",
+                index + 1,
+                glyph.join("\n")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Reads the letters in `rows`, which are drawn with `#` on a `.`
+/// background.
+pub fn recognize(rows: &[impl AsRef<str>]) -> Result<String, OcrError> {
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+
+    let rows = rows
+        .iter()
+        .map(|row| row.as_ref().chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..width.div_ceil(GLYPH_WIDTH + 1))
+        .map(|index| {
+            let glyph = rows
+                .iter()
+                .map(|row| {
+                    (0..GLYPH_WIDTH)
+                        .map(|x| match row.get(index * (GLYPH_WIDTH + 1) + x) {
+                            Some('#') => '#',
+                            _ => '.',
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+
+            FONT.iter()
+                .find(|(_, pixels)| pixels.iter().eq(glyph.iter()))
+                .map(|(letter, _)| *letter)
+                .ok_or(OcrError::UnknownGlyph { index, glyph })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(text: &str) -> Vec<String> {
+        (0..GLYPH_HEIGHT)
+            .map(|y| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, pixels) = FONT.iter().find(|(ch, _)| *ch == letter).unwrap();
+                        format!("{}.", pixels[y])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_recognize() {
+        let alphabet = FONT.iter().map(|(letter, _)| *letter).collect::<String>();
+        assert_eq!(recognize(&draw(&alphabet)), Ok(alphabet));
+
+        // the screen of day10 is 40 pixels wide, which fits 8 letters
+        let picture = draw("PZULBAUA");
+        assert_eq!(picture[0].len(), 40);
+        assert_eq!(recognize(&picture).unwrap(), "PZULBAUA");
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let mut picture = draw("ABC");
+        picture[5].replace_range(5..9, "####");
+
+        let err = recognize(&picture).unwrap_err();
+        assert_eq!(
+            err,
+            OcrError::UnknownGlyph {
+                index: 1,
+                glyph: ["###.", "#..#", "###.", "#..#", "#..#", "####"]
+                    .map(String::from)
+                    .to_vec(),
+            }
+        );
+        assert!(err
+            .to_string()
+            .starts_with("letter 2 is not in the font:\n###."));
+
+        assert_eq!(recognize(&picture[1..]), Err(OcrError::Height(5)));
+    }
+}
@@ -0,0 +1,117 @@
+//! Runs several days at once on worker threads, so that the whole year can
+//! be checked in a single process.
+
+use std::{
+    any::Any,
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, Once,
+    },
+    thread,
+};
+
+use crate::DynSolution;
+
+thread_local! {
+    /// Set on the worker threads, whose panics are reported with the results
+    /// rather than printed as they happen.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that it stays silent on the worker threads, and
+/// keeps their panic messages from garbling the output of the other days.
+fn install_quiet_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Calls `task` for every solution on `jobs` worker threads, and returns the
+/// results in the order of `solutions`. A task that panics is reported by
+/// its panic message, and the workers carry on with the remaining days.
+pub fn for_each_day<T: Send>(
+    solutions: &[&dyn DynSolution],
+    jobs: usize,
+    task: impl Fn(&dyn DynSolution) -> T + Sync,
+) -> Vec<Result<T, String>> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(
+        std::iter::repeat_with(|| None)
+            .take(solutions.len())
+            .collect::<Vec<_>>(),
+    );
+
+    install_quiet_hook();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, solutions.len().max(1)) {
+            scope.spawn(|| {
+                QUIET.with(|quiet| quiet.set(true));
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(solution) = solutions.get(index) else {
+                        break;
+                    };
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| task(*solution)))
+                        .map_err(|payload| panic_message(payload.as_ref()));
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every day is taken by a worker"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked without a message".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_for_each_day() {
+        let results = for_each_day(days::ALL, 4, |solution| {
+            if solution.day() == 3 {
+                panic!("day 3 gave up");
+            }
+            solution.day()
+        });
+
+        assert_eq!(results.len(), days::ALL.len());
+        for (solution, result) in days::ALL.iter().zip(results) {
+            match solution.day() {
+                3 => assert_eq!(result, Err("day 3 gave up".to_string())),
+                day => assert_eq!(result, Ok(day)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_worker() {
+        let results = for_each_day(&days::ALL[..2], 1, |solution| solution.sample().len());
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(for_each_day(&[], 8, |solution| solution.day()).is_empty());
+    }
+}
@@ -0,0 +1,320 @@
+//! Shared helpers for turning puzzle inputs into typed values, reporting
+//! where and why an input could not be understood.
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number within the whole input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// An error for input that stopped before `expected` showed up.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: input.lines().count() + 1,
+            column: 1,
+            expected: expected.into(),
+            found: "end of input".to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the input, remembering where it came from so that errors can
+/// point back at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Reports `expected` at the start of `fragment`, which must be a slice
+    /// of this line. An empty fragment is reported as the end of the line.
+    pub fn error(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: if fragment.is_empty() {
+                "end of line".to_string()
+            } else {
+                format!("{:?}", fragment)
+            },
+        }
+    }
+
+    /// Parses `fragment`, a slice of this line, as a `T`.
+    pub fn parse<T: FromStr>(&self, fragment: &str, expected: &str) -> Result<T, ParseError> {
+        fragment.parse().map_err(|_| self.error(fragment, expected))
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(&self.text[self.text.len()..], format!("{:?}", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(self.text, format!("{:?}", prefix)))
+    }
+
+    /// Returns the whole line, provided every character is `valid`.
+    pub fn chars(
+        &self,
+        expected: &str,
+        valid: impl Fn(char) -> bool,
+    ) -> Result<&'a str, ParseError> {
+        match self.text.char_indices().find(|(_, ch)| !valid(*ch)) {
+            Some((index, ch)) => {
+                Err(self.error(&self.text[index..index + ch.len_utf8()], expected))
+            }
+            None => Ok(self.text),
+        }
+    }
+
+    /// Splits on whitespace, failing unless there are exactly `N` words.
+    pub fn words<const N: usize>(&self, expected: &str) -> Result<[&'a str; N], ParseError> {
+        let mut words = self.text.split_whitespace();
+        let mut result = [""; N];
+        for slot in result.iter_mut() {
+            *slot = words
+                .next()
+                .ok_or_else(|| self.error(&self.text[self.text.len()..], expected))?;
+        }
+        match words.next() {
+            Some(extra) => Err(self.error(extra, "end of line")),
+            None => Ok(result),
+        }
+    }
+}
+
+/// Numbers the lines of `input`, skipping leading and trailing blank lines
+/// while keeping the original line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    let first = input
+        .lines()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    let last = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .last()
+        .map_or(0, |(index, _)| index + 1);
+
+    input
+        .lines()
+        .enumerate()
+        .take(last)
+        .skip(first)
+        .map(|(index, text)| Line {
+            number: index + 1,
+            text,
+        })
+}
+
+/// Parses each line of `input` with `item`, for inputs that list at least
+/// one `expected` item, one per line.
+pub fn each_line<'a, T>(
+    input: &'a str,
+    expected: &str,
+    item: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let items = lines(input).map(item).collect::<Result<Vec<_>, _>>()?;
+    if items.is_empty() {
+        return Err(ParseError::end_of_input(input, expected));
+    }
+    Ok(items)
+}
+
+/// Parses a rectangular grid with one cell per character, where `cell`
+/// returns `None` for characters that are not `expected`.
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+
+    lines(input)
+        .map(|line| {
+            let row = line
+                .text
+                .char_indices()
+                .map(|(index, ch)| {
+                    cell(ch).ok_or_else(|| {
+                        line.error(&line.text[index..index + ch.len_utf8()], expected)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    let end = line
+                        .text
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.text.len(), |(index, _)| index);
+                    return Err(line.error(&line.text[end..], format!("a row of {} cells", width)));
+                }
+                _ => {}
+            }
+            Ok(row)
+        })
+        .collect()
+}
+
+/// Groups the lines of `input` into the blocks separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let lines = lines("\n\nab\n\ncd\n\n").collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                Line {
+                    number: 3,
+                    text: "ab"
+                },
+                Line {
+                    number: 4,
+                    text: ""
+                },
+                Line {
+                    number: 5,
+                    text: "cd"
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_each_line() {
+        let parse = |input| {
+            each_line(input, "a number", |line| {
+                line.parse::<i32>(line.text, "a number")
+            })
+        };
+        assert_eq!(parse("\n1\n2\n"), Ok(vec![1, 2]));
+        assert_eq!(parse("\n1\nx\n").unwrap_err().line, 3);
+        assert_eq!(
+            parse("\n\n").unwrap_err(),
+            ParseError::end_of_input("\n\n", "a number")
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("1\n2\n\n3\n");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0].number, 4);
+    }
+
+    #[test]
+    fn test_error_position() {
+        let line = Line {
+            number: 7,
+            text: "move 1 from x to 3",
+        };
+        let err = line.parse::<usize>(&line.text[12..13], "a stack number");
+        assert_eq!(
+            err,
+            Err(ParseError {
+                line: 7,
+                column: 13,
+                expected: "a stack number".to_string(),
+                found: "\"x\"".to_string(),
+            })
+        );
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 7, column 13: expected a stack number, found \"x\""
+        );
+
+        let err = line.split_once(",").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (19, "end of line"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |ch: char| ch.to_digit(10);
+        assert_eq!(
+            grid("12\n34\n", "a digit", digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+
+        let err = grid("12\n3x\n", "a digit", digit).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 2, "a digit")
+        );
+
+        let err = grid("12\n345\n", "a digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "\"5\""));
+        let err = grid("12\n3\n", "a digit", digit).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (2, 2, "end of line")
+        );
+    }
+
+    #[test]
+    fn test_words() {
+        let line = Line {
+            number: 1,
+            text: "A Y",
+        };
+        assert_eq!(line.words::<2>("a shape"), Ok(["A", "Y"]));
+        assert_eq!(line.words::<3>("a shape").unwrap_err().column, 4);
+        assert_eq!(line.words::<1>("a shape").unwrap_err().column, 3);
+    }
+}
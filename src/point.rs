@@ -0,0 +1,307 @@
+//! Points on the plane and in space, and the four directions of a screen
+//! where `y` grows downwards.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: From<i8>> Point2<T> {
+    /// Offsets to all eight neighbours, clockwise from up.
+    pub fn around() -> [Self; 8] {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .map(|(x, y)| Self::new(T::from(x), T::from(y)))
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> Point2<T> {
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction.delta())
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Self::around().into_iter().map(move |delta| self + delta)
+    }
+}
+
+impl Point2<usize> {
+    /// Moves by a signed `delta`, unless that would leave the unsigned range.
+    pub fn checked_add_signed(self, delta: Point2<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> Point3<T> {
+    /// The six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |(x, y, z)| self + Self::new(T::from(x), T::from(y), T::from(z)))
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $point { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $point { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $point { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+macro_rules! impl_distances {
+    ($($t:ty),+) => {
+        $(
+            impl Point2<$t> {
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as $t
+                }
+
+                /// The number of king moves between the points.
+                pub fn chebyshev(self, other: Self) -> $t {
+                    self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)) as $t
+                }
+            }
+
+            impl Point3<$t> {
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x.abs_diff(other.x)
+                        + self.y.abs_diff(other.y)
+                        + self.z.abs_diff(other.z)) as $t
+                }
+
+                pub fn chebyshev(self, other: Self) -> $t {
+                    self.x
+                        .abs_diff(other.x)
+                        .max(self.y.abs_diff(other.y))
+                        .max(self.z.abs_diff(other.z)) as $t
+                }
+            }
+        )+
+    };
+}
+
+impl_distances!(i32, i64, isize, usize);
+
+macro_rules! impl_signum {
+    ($($t:ty),+) => {
+        $(
+            impl Point2<$t> {
+                /// Shrinks every coordinate to -1, 0 or 1, keeping its sign.
+                pub fn signum(self) -> Self {
+                    Self::new(self.x.signum(), self.y.signum())
+                }
+            }
+        )+
+    };
+}
+
+impl_signum!(i32, i64, isize);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The step taken when moving one tile this way.
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Point2 = Point2::new(1, -2);
+        let b: Point2 = Point2::new(3, 4);
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(-2, -6));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+        assert_eq!((b - a).signum(), Point2::new(1, 1));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a: Point2 = Point2::new(1, -2);
+        let b: Point2 = Point2::new(3, 4);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point2::<usize>::new(5, 0).manhattan(Point2::new(2, 1)), 4);
+        assert_eq!(
+            Point3::<i32>::default().manhattan(Point3::new(-1, 2, -3)),
+            6
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin: Point2 = Point2::default();
+        assert_eq!(origin.neighbours4().count(), 4);
+        assert!(origin.neighbours8().all(|p| p.chebyshev(origin) == 1));
+        assert_eq!(
+            Point3::<i32>::default()
+                .neighbours6()
+                .filter(|p| p.manhattan(Point3::default()) == 1)
+                .count(),
+            6
+        );
+        assert_eq!(
+            Point2::<usize>::new(0, 1).checked_add_signed(Direction::Up.delta()),
+            Some(Point2::new(0, 0))
+        );
+        assert_eq!(
+            Point2::<usize>::new(0, 1).checked_add_signed(Direction::Left.delta()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::Down.delta::<i32>(), Point2::new(0, 1));
+        assert!(Direction::ALL
+            .into_iter()
+            .all(|d| d.delta::<i32>() == -d.opposite().delta::<i32>()));
+    }
+}
@@ -0,0 +1,109 @@
+//! A small seeded random number generator, so that random inputs can be
+//! reproduced from their seed without pulling in a dependency.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64, which is fast and good enough for generating test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, which must not be empty.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "cannot pick below 0");
+        // Lemire's multiply and shift, whose bias is negligible here
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let offset = match range.end().abs_diff(*range.start()).checked_add(1) {
+            Some(span) => self.below(span),
+            // the range covers every i64
+            None => self.next_u64(),
+        };
+        range.start().wrapping_add(offset as i64)
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles `items` in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        unit < probability
+    }
+}
+
+/// Checks `property` against `cases` generators seeded one after the other,
+/// naming the failing seed so that the case can be replayed.
+#[cfg(test)]
+pub(crate) fn check(cases: u64, mut property: impl FnMut(&mut Rng)) {
+    for seed in 0..cases {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            property(&mut Rng::new(seed))
+        }));
+        if let Err(err) = result {
+            eprintln!("property failed with seed {}", seed);
+            std::panic::resume_unwind(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        check(20, |rng| {
+            assert!(rng.below(3) < 3);
+            assert!((-5..=5).contains(&rng.range(-5..=5)));
+            assert_eq!(rng.range(4..=4), 4);
+            rng.range(i64::MIN..=i64::MAX);
+            assert!(['a', 'b'].contains(rng.pick(&['a', 'b'])));
+
+            let mut items = (0..10).collect::<Vec<_>>();
+            rng.shuffle(&mut items);
+            items.sort();
+            assert_eq!(items, (0..10).collect::<Vec<_>>());
+        });
+
+        let mut rng = Rng::new(1);
+        let hits = (0..1000).filter(|_| rng.chance(0.25)).count();
+        assert!((150..350).contains(&hits));
+    }
+}
@@ -0,0 +1,354 @@
+//! Generates a new day from the `empty` template and registers it.
+//!
+//! The 2022 days predate multi-year support, so they keep their
+//! `src/days/dayNN.rs` modules and `dayNN` binaries. Days of any other year
+//! go into `src/years/yYYYY/dayNN.rs`, with `YYYY-dayNN` binaries.
+
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("days/empty.rs");
+const BIN_TEMPLATE: &str = include_str!("bin/empty/main.rs");
+const SAMPLE_TEMPLATE: &str = include_str!("bin/empty/sample.txt");
+
+/// The year whose days live in the `days` module.
+const FIRST_YEAR: u16 = 2022;
+
+fn module_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn struct_name(day: u8) -> String {
+    format!("Day{:02}", day)
+}
+
+/// The module holding the days of `year`, if it is not `days`.
+fn year_module_name(year: u16) -> Option<String> {
+    (year != FIRST_YEAR).then(|| format!("y{}", year))
+}
+
+fn bin_name(year: u16, day: u8) -> String {
+    match year_module_name(year) {
+        None => module_name(day),
+        Some(..) => format!("{}-{}", year, module_name(day)),
+    }
+}
+
+fn render_day(year: u16, day: u8) -> String {
+    // the template sits in `src/days`, one level shallower than later years
+    let bin_dir = match year_module_name(year) {
+        None => format!("\"../bin/{}/", bin_name(year, day)),
+        Some(..) => format!("\"../../bin/{}/", bin_name(year, day)),
+    };
+    DAY_TEMPLATE
+        .replace("Empty", &struct_name(day))
+        .replace(
+            "const YEAR: u16 = 0;",
+            &format!("const YEAR: u16 = {};", year),
+        )
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+        .replace("\"../bin/empty/", &bin_dir)
+}
+
+fn render_bin(year: u16, day: u8) -> String {
+    let path = |module: &str| format!("{}::{}::{}", module, module_name(day), struct_name(day));
+    // imports stay in the order rustfmt sorts them in
+    let imports = match year_module_name(year) {
+        None => format!("{{{}, run}}", path("days")),
+        Some(year_module) => format!("{{run, {}}}", path(&format!("years::{}", year_module))),
+    };
+    BIN_TEMPLATE
+        .replace("{days::empty::Empty, run}", &imports)
+        .replace("Empty", &struct_name(day))
+}
+
+/// The registry of a year seen for the first time, holding only `day`.
+fn render_year(year: u16, day: u8) -> String {
+    let module = module_name(day);
+    format!(
+        "use crate::DynSolution;\n\npub mod {module};\n\n\
+         /// Every implemented day of {year}, in order.\n\
+         pub const ALL: &[&dyn DynSolution] = &[\n    \
+         // one line per day, so that `aoc new` can add to the list\n    \
+         &{module}::{name},\n];\n",
+        module = module,
+        year = year,
+        name = struct_name(day)
+    )
+}
+
+/// Inserts `line` among the lines for which `is_sibling` holds, keeping them
+/// sorted. Without any sibling yet, `line` goes right after the first line
+/// for which `is_anchor` holds, or at the end.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    line: String,
+    is_sibling: impl Fn(&str) -> bool,
+    is_anchor: impl Fn(&str) -> bool,
+) {
+    let siblings = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| is_sibling(existing))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let position = siblings
+        .iter()
+        .find(|index| lines[**index] > line)
+        .copied()
+        .or_else(|| siblings.last().map(|index| index + 1))
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|existing| is_anchor(existing))
+                .map(|index| index + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(position, line);
+}
+
+/// Adds the day's `mod` declaration and registry entry to the contents of
+/// its year's `mod.rs`, keeping both lists sorted.
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let module = module_name(day);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    &{}::{},", module, struct_name(day));
+
+    if registry.lines().any(|line| line == declaration) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    insert_sorted(
+        &mut lines,
+        declaration,
+        |line| line.starts_with("pub mod day"),
+        |_| false,
+    );
+    insert_sorted(
+        &mut lines,
+        entry,
+        |line| line.starts_with("    &day"),
+        |_| false,
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the year's `mod` declaration and registry entry to the contents of
+/// `years.rs`, keeping both lists sorted.
+fn register_year(registry: &str, year: u16) -> Result<String, String> {
+    let module = year_module_name(year).ok_or("the first year is always registered")?;
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("    Year::new({}, {}::ALL),", year, module);
+
+    if registry.lines().any(|line| line == declaration) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    insert_sorted(
+        &mut lines,
+        declaration,
+        |line| line.starts_with("pub mod y"),
+        |line| line.starts_with("// the years after"),
+    );
+    insert_sorted(
+        &mut lines,
+        entry,
+        |line| line.starts_with("    Year::new("),
+        |_| false,
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+}
+
+/// Creates the day's module, binary and sample under `root`, and registers
+/// the day, along with its year if this is the year's first day. Nothing is
+/// written if any of the new files already exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let module = module_name(day);
+    let bin = bin_name(year, day);
+    let src = root.join("src");
+    let year_dir = match year_module_name(year) {
+        None => src.join("days"),
+        Some(year_module) => src.join("years").join(year_module),
+    };
+    let registry_path = year_dir.join("mod.rs");
+
+    let mut files = vec![
+        (
+            year_dir.join(format!("{}.rs", module)),
+            render_day(year, day),
+        ),
+        (
+            src.join("bin").join(&bin).join("main.rs"),
+            render_bin(year, day),
+        ),
+        (
+            src.join("bin").join(&bin).join("sample.txt"),
+            SAMPLE_TEMPLATE.to_string(),
+        ),
+    ];
+    let mut updates = vec![];
+    if registry_path.exists() {
+        updates.push((
+            registry_path.clone(),
+            register(&read(&registry_path)?, day)?,
+        ));
+    } else {
+        let years_path = src.join("years.rs");
+        updates.push((
+            years_path.clone(),
+            register_year(&read(&years_path)?, year)?,
+        ));
+        files.push((registry_path, render_year(year, day)));
+    }
+
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    let mut written = vec![];
+    for (path, contents) in files {
+        let parent = path.parent().unwrap();
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        std::fs::write(&path, contents)
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+        written.push(path);
+    }
+    for (path, contents) in updates {
+        std::fs::write(&path, contents)
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_day() {
+        let rendered = render_day(2022, 15);
+        assert!(rendered.contains("pub struct Day15;"));
+        assert!(rendered.contains("impl Solution for Day15 {"));
+        assert!(rendered.contains("const YEAR: u16 = 2022;"));
+        assert!(rendered.contains("const DAY: u8 = 15;"));
+        assert!(rendered.contains("include_str!(\"../bin/day15/sample.txt\")"));
+        assert!(rendered.contains("assert_actual::<Day15>(Part::One);"));
+        assert!(!rendered.contains("Empty"));
+        assert!(!rendered.contains("empty"));
+
+        let rendered = render_day(2023, 1);
+        assert!(rendered.contains("const YEAR: u16 = 2023;"));
+        assert!(rendered.contains("include_str!(\"../../bin/2023-day01/sample.txt\")"));
+    }
+
+    #[test]
+    fn test_render_bin() {
+        let rendered = render_bin(2022, 7);
+        assert!(rendered.contains("days::day07::Day07"));
+        assert!(rendered.contains("run::<Day07>()"));
+        assert!(!rendered.contains("Empty"));
+
+        assert!(render_bin(2023, 7).contains("years::y2023::day07::Day07"));
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = "\
+pub mod day01;
+pub mod day14;
+pub mod day18;
+
+pub mod empty;
+
+pub const ALL: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day14::Day14,
+    &day18::Day18,
+];
+";
+        assert_eq!(
+            register(registry, 15).unwrap(),
+            "\
+pub mod day01;
+pub mod day14;
+pub mod day15;
+pub mod day18;
+
+pub mod empty;
+
+pub const ALL: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day14::Day14,
+    &day15::Day15,
+    &day18::Day18,
+];
+"
+        );
+        assert!(register(registry, 14).is_err());
+        assert!(register(registry, 25)
+            .unwrap()
+            .contains("pub mod day18;\npub mod day25;\n"));
+        assert!(register(registry, 25)
+            .unwrap()
+            .contains("    &day18::Day18,\n    &day25::Day25,\n"));
+    }
+
+    const YEARS: &str = "\
+use crate::{days, DynSolution};
+
+// the years after 2022, added by `aoc new --year`
+
+pub const ALL: &[Year] = &[
+    // one line per year, so that `aoc new` can add to the list
+    Year::new(2022, days::ALL),
+];
+";
+
+    #[test]
+    fn test_register_year() {
+        let registry = YEARS;
+        let registered = register_year(registry, 2023).unwrap();
+        assert!(registered
+            .contains("// the years after 2022, added by `aoc new --year`\npub mod y2023;\n"));
+        assert!(registered
+            .contains("    Year::new(2022, days::ALL),\n    Year::new(2023, y2023::ALL),\n"));
+
+        let registered = register_year(&registered, 2021).unwrap();
+        assert!(registered.contains("pub mod y2021;\npub mod y2023;\n"));
+        assert!(registered
+            .contains("    Year::new(2021, y2021::ALL),\n    Year::new(2022, days::ALL),\n"));
+        assert!(register_year(&registered, 2023).is_err());
+        assert!(register_year(registry, 2022).is_err());
+    }
+
+    #[test]
+    fn test_new_day_of_new_year() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/years.rs"), YEARS).unwrap();
+
+        let written = new_day(&root, 2023, 2).unwrap();
+        assert!(written.contains(&root.join("src/years/y2023/day02.rs")));
+        assert!(written.contains(&root.join("src/bin/2023-day02/main.rs")));
+        new_day(&root, 2023, 1).unwrap();
+        assert!(new_day(&root, 2023, 1).is_err());
+
+        let registry = std::fs::read_to_string(root.join("src/years/y2023/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\n"));
+        assert!(registry.contains("    &day01::Day01,\n    &day02::Day02,\n"));
+        let years = std::fs::read_to_string(root.join("src/years.rs")).unwrap();
+        assert_eq!(years.matches("y2023").count(), 2);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,275 @@
+//! Shortest path searches over implicit graphs, described by a successor
+//! function instead of an adjacency structure.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A shortest path, from one of the sources to the first goal reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node along the way, including both ends.
+    pub nodes: Vec<N>,
+}
+
+/// Interns nodes so that the queues and parent links only hold indices.
+struct Visited<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// The best known cost to reach each node, and where it came from.
+    best: Vec<(C, Option<usize>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            index: HashMap::new(),
+            best: vec![],
+        }
+    }
+
+    /// Records reaching `node` at `cost` unless it is already known at a
+    /// cost that is no worse, returning its index if it was recorded.
+    fn reach(&mut self, node: N, cost: C, parent: Option<usize>) -> Option<usize>
+    where
+        C: Ord,
+    {
+        match self.index.get(&node) {
+            Some(&i) if self.best[i].0 <= cost => None,
+            Some(&i) => {
+                self.best[i] = (cost, parent);
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(node.clone(), i);
+                self.nodes.push(node);
+                self.best.push((cost, parent));
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes = vec![];
+        let mut current = Some(goal);
+        while let Some(i) = current {
+            nodes.push(self.nodes[i].clone());
+            current = self.best[i].1;
+        }
+        nodes.reverse();
+
+        Path {
+            cost: self.best[goal].0,
+            nodes,
+        }
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = sources
+        .into_iter()
+        .filter_map(|source| visited.reach(source, 0, None))
+        .collect::<VecDeque<_>>();
+
+    while let Some(current) = queue.pop_front() {
+        if is_goal(&visited.nodes[current]) {
+            return Some(visited.path(current));
+        }
+
+        let cost = visited.best[current].0 + 1;
+        for next in successors(&visited.nodes[current]) {
+            if !visited.index.contains_key(&next) {
+                queue.extend(visited.reach(next, cost, Some(current)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, for successors that come with a step cost.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(sources, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. The `heuristic` must never overestimate the remaining cost
+/// to a goal, or the path found may not be the shortest.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut queue = BinaryHeap::new();
+
+    for source in sources {
+        let estimate = heuristic(&source);
+        if let Some(i) = visited.reach(source, C::default(), None) {
+            queue.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, current))) = queue.pop() {
+        if cost > visited.best[current].0 {
+            // a cheaper way here was found after this entry was queued
+            continue;
+        }
+        if is_goal(&visited.nodes[current]) {
+            return Some(visited.path(current));
+        }
+
+        for (next, step) in successors(&visited.nodes[current]) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+            if let Some(i) = visited.reach(next, cost, Some(current)) {
+                queue.push(Reverse((estimate, cost, i)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node that can be reached from the sources, including themselves.
+pub fn reachable<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut queue = sources
+        .into_iter()
+        .filter(|source| seen.insert(source.clone()))
+        .collect::<VecDeque<_>>();
+
+    while let Some(current) = queue.pop_front() {
+        for next in successors(&current) {
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid::Grid, point::Point2};
+
+    const MAZE: &str = r"
+..#...
+.##.#.
+....#.
+";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, "a wall or ground", |ch| match ch {
+            '#' => Some(false),
+            '.' => Some(true),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let open = |pos: &Point2<usize>| {
+            maze.neighbours4(*pos)
+                .filter(|next| maze[*next])
+                .collect::<Vec<_>>()
+        };
+        let goal = Point2::new(5, 0);
+
+        let path = bfs([Point2::new(0, 0)], open, |pos| *pos == goal).unwrap();
+        assert_eq!(path.cost, 9);
+        assert_eq!(path.nodes.len(), 10);
+        assert_eq!(path.nodes[0], Point2::new(0, 0));
+        assert_eq!(path.nodes[9], goal);
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| step[0].manhattan(step[1]) == 1));
+
+        let path = bfs([Point2::new(0, 0), Point2::new(3, 2)], open, |pos| {
+            *pos == goal
+        });
+        assert_eq!(path.map(|path| path.cost), Some(4));
+
+        assert_eq!(bfs([Point2::new(0, 0)], open, |_| false), None);
+    }
+
+    #[test]
+    fn test_weighted() {
+        // going around 0 -> 1 -> 2 -> 3 is cheaper than the direct edge
+        let edges = |node: &u32| match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        };
+
+        let path = dijkstra([0], edges, |node| *node == 3).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 6,
+                nodes: vec![0, 1, 2, 3]
+            }
+        );
+        assert_eq!(
+            astar([0], edges, |node| 3 - node, |node| *node == 3),
+            Some(path)
+        );
+    }
+
+    #[test]
+    fn test_reachable() {
+        let maze = maze();
+        let reached = reachable([Point2::new(0, 0)], |pos| {
+            maze.neighbours4(*pos)
+                .filter(|next| maze[*next])
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(
+            reached.len(),
+            maze.iter().filter(|(_, open)| **open).count()
+        );
+    }
+}
@@ -0,0 +1,210 @@
+//! Captures the steps of the simulation days as frames, and plays them back
+//! in the terminal or writes them out as numbered text or PPM files.
+
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{grid::Grid, point::Point2};
+
+/// One step of a simulation, one character per tile.
+pub type Frame = Grid<char>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// Redraws every frame in place, waiting `delay` in between.
+    Terminal { delay: Duration },
+    /// Writes `frame00001.txt`, `frame00002.txt`, ... into the directory.
+    Text(PathBuf),
+    /// Writes `frame00001.ppm`, ... into the directory, drawing each tile
+    /// as a `scale`x`scale` square.
+    Ppm { dir: PathBuf, scale: usize },
+}
+
+/// Receives the frames of a simulation, and tells it when to stop.
+pub struct Recorder {
+    output: Output,
+    limit: Option<usize>,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    /// Records at most `limit` frames, if given.
+    pub fn new(output: Output, limit: Option<usize>) -> Self {
+        Self {
+            output,
+            limit,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// Records the next frame, returning `false` once the simulation should
+    /// stop, either because enough frames were recorded or because writing
+    /// failed.
+    pub fn record(&mut self, frame: &Frame) -> bool {
+        if !self.wants_more() {
+            return false;
+        }
+
+        self.frames += 1;
+        if let Err(err) = self.write(frame) {
+            self.error = Some(err);
+        }
+        self.wants_more()
+    }
+
+    /// The number of frames recorded, or the first error met while writing.
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.frames),
+        }
+    }
+
+    fn wants_more(&self) -> bool {
+        self.error.is_none() && self.limit.is_none_or(|limit| self.frames < limit)
+    }
+
+    fn write(&self, frame: &Frame) -> io::Result<()> {
+        match &self.output {
+            Output::Terminal { delay } => {
+                let mut stdout = io::stdout().lock();
+                // clear the screen and move the cursor back to the top
+                write!(stdout, "\x1b[2J\x1b[H{}\nframe {}\n", frame, self.frames)?;
+                stdout.flush()?;
+                std::thread::sleep(*delay);
+                Ok(())
+            }
+            Output::Text(dir) => {
+                std::fs::create_dir_all(dir)?;
+                std::fs::write(
+                    dir.join(format!("frame{:05}.txt", self.frames)),
+                    format!("{}\n", frame),
+                )
+            }
+            Output::Ppm { dir, scale } => {
+                std::fs::create_dir_all(dir)?;
+                std::fs::write(
+                    dir.join(format!("frame{:05}.ppm", self.frames)),
+                    ppm(frame, *scale),
+                )
+            }
+        }
+    }
+}
+
+/// A fixed colour for every kind of tile, so that frames stay comparable.
+fn colour(tile: char) -> [u8; 3] {
+    match tile {
+        '.' | ' ' => [16, 16, 32],
+        '#' => [200, 200, 200],
+        'o' => [230, 190, 80],
+        '@' | 'E' | 'H' => [240, 70, 70],
+        _ => {
+            let hash = (tile as u32).wrapping_mul(2_654_435_761);
+            [
+                (hash >> 24) as u8 | 0x40,
+                (hash >> 16) as u8 | 0x40,
+                (hash >> 8) as u8 | 0x40,
+            ]
+        }
+    }
+}
+
+/// Encodes `frame` as a binary PPM image.
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )
+    .into_bytes();
+
+    for row in frame.rows() {
+        let pixels = row
+            .iter()
+            .flat_map(|tile| std::iter::repeat_n(colour(*tile), scale))
+            .flatten()
+            .collect::<Vec<_>>();
+        (0..scale).for_each(|_| image.extend_from_slice(&pixels));
+    }
+
+    image
+}
+
+/// Draws `tiles` onto a frame just large enough to hold them, filling the
+/// rest with `background`. Later tiles are drawn over earlier ones.
+pub fn plot(tiles: impl IntoIterator<Item = (Point2, char)>, background: char) -> Frame {
+    let tiles = tiles.into_iter().collect::<Vec<_>>();
+    if tiles.is_empty() {
+        return Frame::new(0, 0, background);
+    }
+
+    let min_x = tiles.iter().map(|(point, _)| point.x).min().unwrap();
+    let max_x = tiles.iter().map(|(point, _)| point.x).max().unwrap();
+    let min_y = tiles.iter().map(|(point, _)| point.y).min().unwrap();
+    let max_y = tiles.iter().map(|(point, _)| point.y).max().unwrap();
+
+    let mut frame = Frame::new(
+        (max_x - min_x + 1) as usize,
+        (max_y - min_y + 1) as usize,
+        background,
+    );
+    for (point, tile) in tiles {
+        frame[Point2::new((point.x - min_x) as usize, (point.y - min_y) as usize)] = tile;
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plot() {
+        let frame = plot(
+            [
+                (Point2::new(-1, 5), '#'),
+                (Point2::new(1, 6), 'o'),
+                (Point2::new(-1, 5), 'H'),
+            ],
+            '.',
+        );
+        assert_eq!(frame.to_string(), "H..\n..o");
+        assert_eq!(plot([], '.').to_string(), "");
+    }
+
+    #[test]
+    fn test_ppm() {
+        let frame = plot([(Point2::new(0, 0), '#'), (Point2::new(1, 0), '.')], '.');
+        let image = ppm(&frame, 2);
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 4 * 2 * 3);
+        assert_eq!(image[header.len()..header.len() + 3], colour('#'));
+        assert_eq!(image[header.len() + 6..header.len() + 9], colour('.'));
+    }
+
+    #[test]
+    fn test_recorder_limit() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut recorder = Recorder::new(Output::Text(dir.clone()), Some(2));
+        let frame = plot([(Point2::new(0, 0), '#')], '.');
+
+        assert!(recorder.record(&frame));
+        assert!(!recorder.record(&frame));
+        assert!(!recorder.record(&frame));
+        assert_eq!(recorder.finish().unwrap(), 2);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("frame00002.txt")).unwrap(),
+            "#\n"
+        );
+        assert!(!dir.join("frame00003.txt").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
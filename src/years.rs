@@ -0,0 +1,77 @@
+//! The registry of every implemented day, keyed by year and day. The 2022
+//! days predate multi-year support and live in [`crate::days`], while
+//! every later year gets a `yYYYY` module of its own here.
+
+use crate::{days, DynSolution};
+
+// the years after 2022, added by `aoc new --year`
+
+/// The implemented days of one year, in order.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn DynSolution],
+}
+
+impl Year {
+    pub const fn new(year: u16, days: &'static [&'static dyn DynSolution]) -> Self {
+        Self { year, days }
+    }
+}
+
+/// Every year with implemented days, in order.
+pub const ALL: &[Year] = &[
+    // one line per year, so that `aoc new` can add to the list
+    Year::new(2022, days::ALL),
+];
+
+/// The most recent year, which commands default to when none is given.
+pub fn latest() -> u16 {
+    ALL.last().map_or(2022, |year| year.year)
+}
+
+/// The implemented days of `year`, in order.
+pub fn days(year: u16) -> Option<&'static [&'static dyn DynSolution]> {
+    ALL.iter()
+        .find(|entry| entry.year == year)
+        .map(|entry| entry.days)
+}
+
+/// Every implemented day of every year, in order.
+pub fn all() -> impl Iterator<Item = &'static dyn DynSolution> {
+    ALL.iter().flat_map(|year| year.days.iter().copied())
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    days(year)?
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_ordered() {
+        assert!(ALL.windows(2).all(|pair| pair[0].year < pair[1].year));
+        for year in ALL {
+            assert!(year
+                .days
+                .iter()
+                .all(|solution| solution.year() == year.year));
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let solution = find(2022, 12).unwrap();
+        assert_eq!((solution.year(), solution.day()), (2022, 12));
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 1).is_none());
+        assert_eq!(
+            all().count(),
+            ALL.iter().map(|year| year.days.len()).sum::<usize>()
+        );
+    }
+}